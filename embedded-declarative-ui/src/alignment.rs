@@ -1,3 +1,6 @@
+use crate::environment::LayoutDirection;
+use embedded_graphics::prelude::{Point, Size};
+
 #[allow(dead_code)]
 #[derive(Clone, Copy)]
 pub enum VerticalAlignment {
@@ -6,10 +9,64 @@ pub enum VerticalAlignment {
     Center,
 }
 
+/// Horizontal alignment, mirrored for right-to-left layouts.
+///
+/// `Leading` and `Trailing` name the mirrored edges explicitly. `Left` and `Right` are the same
+/// as `Leading` and `Trailing`, so existing screens keep mirroring.
 #[allow(dead_code)]
 #[derive(Clone, Copy)]
 pub enum HorizontalAlignment {
     Left,
     Right,
     Center,
+    Leading,
+    Trailing,
+}
+
+impl VerticalAlignment {
+    pub(crate) fn offset(self, available_height: u32, content_height: u32) -> i32 {
+        match self {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Bottom => available_height as i32 - content_height as i32,
+            VerticalAlignment::Center => (available_height as i32 - content_height as i32) / 2,
+        }
+    }
+}
+
+impl HorizontalAlignment {
+    pub(crate) fn offset(
+        self,
+        available_width: u32,
+        content_width: u32,
+        layout_direction: LayoutDirection,
+    ) -> i32 {
+        let leading = 0;
+        let trailing = available_width as i32 - content_width as i32;
+
+        match (self, layout_direction) {
+            (HorizontalAlignment::Center, _) => trailing / 2,
+            (
+                HorizontalAlignment::Left | HorizontalAlignment::Leading,
+                LayoutDirection::LeftToRight,
+            )
+            | (
+                HorizontalAlignment::Right | HorizontalAlignment::Trailing,
+                LayoutDirection::RightToLeft,
+            ) => leading,
+            _ => trailing,
+        }
+    }
+}
+
+pub(crate) fn aligned_offset(
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    available_size: Size,
+    content_size: Size,
+    layout_direction: LayoutDirection,
+) -> Point {
+    Point::new(
+        horizontal_alignment.offset(available_size.width, content_size.width, layout_direction),
+        vertical_alignment.offset(available_size.height, content_size.height),
+    )
 }
@@ -0,0 +1,322 @@
+use crate::state::{Observable, Readable};
+use core::cell::Cell;
+use embedded_graphics::{
+    pixelcolor::{
+        Bgr555, Bgr565, Bgr888, BinaryColor, Gray2, Gray4, Gray8, Rgb555, Rgb565, Rgb888,
+    },
+    prelude::{GrayColor, Point, RgbColor, Size},
+};
+
+/// Monotonic time source in milliseconds, e.g. `|| Instant::now().as_millis()` with embassy-time.
+pub trait Clock {
+    fn now_ms(&self) -> u64;
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> u64,
+{
+    fn now_ms(&self) -> u64 {
+        self()
+    }
+}
+
+/// Maps the linear progress of an animation to the progress of its value.
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// Overshoots the target slightly before settling, approximating a spring without
+    /// the floating point functions unavailable in `no_std`.
+    Spring,
+}
+
+impl Easing {
+    /// Eased progress for the linear `progress` between 0 and 1.
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => {
+                let inverse = 1.0 - t;
+                1.0 - inverse * inverse * inverse
+            }
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let inverse = 2.0 - 2.0 * t;
+                    1.0 - inverse * inverse * inverse / 2.0
+                }
+            }
+            Easing::Spring => {
+                const OVERSHOOT: f32 = 1.70158;
+                let u = t - 1.0;
+                1.0 + (OVERSHOOT + 1.0) * u * u * u + OVERSHOOT * u * u
+            }
+        }
+    }
+}
+
+/// Values that can be animated by [`Animated`].
+pub trait Interpolate: Copy {
+    /// Value at `progress` of the way from `from` to `to`. `progress` may leave the range
+    /// between 0 and 1 for easings overshooting their target.
+    fn interpolate(from: Self, to: Self, progress: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(from: Self, to: Self, progress: f32) -> Self {
+        from + (to - from) * progress
+    }
+}
+
+impl Interpolate for i32 {
+    fn interpolate(from: Self, to: Self, progress: f32) -> Self {
+        from + round((to - from) as f32 * progress)
+    }
+}
+
+macro_rules! impl_interpolate_unsigned {
+    ($($type:ty),*) => {
+        $(
+            impl Interpolate for $type {
+                fn interpolate(from: Self, to: Self, progress: f32) -> Self {
+                    let value = from as f32 + (to as f32 - from as f32) * progress;
+                    round(value.clamp(0.0, <$type>::MAX as f32)) as $type
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_unsigned!(u8, u16, u32);
+
+impl Interpolate for Point {
+    fn interpolate(from: Self, to: Self, progress: f32) -> Self {
+        Point::new(
+            i32::interpolate(from.x, to.x, progress),
+            i32::interpolate(from.y, to.y, progress),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(from: Self, to: Self, progress: f32) -> Self {
+        Size::new(
+            u32::interpolate(from.width, to.width, progress),
+            u32::interpolate(from.height, to.height, progress),
+        )
+    }
+}
+
+macro_rules! impl_interpolate_rgb {
+    ($($type:ty),*) => {
+        $(
+            impl Interpolate for $type {
+                fn interpolate(from: Self, to: Self, progress: f32) -> Self {
+                    <$type>::new(
+                        u8::interpolate(from.r(), to.r(), progress).min(<$type>::MAX_R),
+                        u8::interpolate(from.g(), to.g(), progress).min(<$type>::MAX_G),
+                        u8::interpolate(from.b(), to.b(), progress).min(<$type>::MAX_B),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_rgb!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888);
+
+macro_rules! impl_interpolate_gray {
+    ($($type:ty),*) => {
+        $(
+            impl Interpolate for $type {
+                fn interpolate(from: Self, to: Self, progress: f32) -> Self {
+                    <$type>::new(
+                        u8::interpolate(from.luma(), to.luma(), progress).min(<$type>::WHITE.luma()),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_gray!(Gray2, Gray4, Gray8);
+
+/// Switches from `from` to `to` halfway through.
+impl Interpolate for BinaryColor {
+    fn interpolate(from: Self, to: Self, progress: f32) -> Self {
+        if progress < 0.5 { from } else { to }
+    }
+}
+
+fn round(value: f32) -> i32 {
+    if value < 0.0 {
+        (value - 0.5) as i32
+    } else {
+        (value + 0.5) as i32
+    }
+}
+
+/// Value moving towards its target over `duration_ms` along an [`Easing`] curve, timed by an injected [`Clock`].
+///
+/// Read it through a [`Watch`](crate::state::Watch) so it stays dirty, and keeps being
+/// redrawn by the [`Renderer`](crate::renderer::Renderer), until the animation finished.
+pub struct Animated<'a, T>
+where
+    T: Interpolate,
+{
+    from: Cell<T>,
+    to: Cell<T>,
+    start_ms: Cell<Option<u64>>,
+    duration_ms: u32,
+    easing: Easing,
+    clock: &'a dyn Clock,
+    dirty: Cell<bool>,
+}
+
+#[allow(dead_code)]
+impl<'a, T> Animated<'a, T>
+where
+    T: Interpolate,
+{
+    pub fn new(value: T, duration_ms: u32, easing: Easing, clock: &'a dyn Clock) -> Self {
+        Self {
+            from: Cell::new(value),
+            to: Cell::new(value),
+            start_ms: Cell::new(None),
+            duration_ms,
+            easing,
+            clock,
+            dirty: Cell::new(true),
+        }
+    }
+
+    /// Current value of the animation.
+    pub fn value(&self) -> T {
+        let Some(progress) = self.progress() else {
+            return self.to.get();
+        };
+
+        T::interpolate(self.from.get(), self.to.get(), self.easing.apply(progress))
+    }
+
+    /// Value the animation is moving towards.
+    pub fn target(&self) -> T {
+        self.to.get()
+    }
+
+    /// Starts animating from the current value towards `target`.
+    pub fn animate_to(&self, target: T) {
+        self.from.set(self.value());
+        self.to.set(target);
+        self.start_ms.set(Some(self.clock.now_ms()));
+        self.dirty.set(true);
+    }
+
+    /// Jumps to `value` without animating.
+    pub fn set(&self, value: T) {
+        self.from.set(value);
+        self.to.set(value);
+        self.start_ms.set(None);
+        self.dirty.set(true);
+    }
+
+    /// Whether the value is still changing, so further frames are needed.
+    pub fn is_animating(&self) -> bool {
+        self.progress().is_some()
+    }
+
+    /// Linear progress between 0 and 1, or `None` once the animation finished.
+    fn progress(&self) -> Option<f32> {
+        let elapsed = self.clock.now_ms().saturating_sub(self.start_ms.get()?);
+
+        (elapsed < self.duration_ms as u64).then(|| elapsed as f32 / self.duration_ms as f32)
+    }
+}
+
+impl<T> Observable for Animated<'_, T>
+where
+    T: Interpolate,
+{
+    fn is_dirty(&self) -> bool {
+        self.dirty.get() || self.is_animating()
+    }
+
+    fn mark_clean(&self) {
+        if !self.is_animating() {
+            self.dirty.set(false);
+        }
+    }
+}
+
+impl<T> Readable<T> for Animated<'_, T>
+where
+    T: Interpolate,
+{
+    fn get(&self) -> T {
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASINGS: [Easing; 5] = [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+        Easing::Spring,
+    ];
+
+    #[test]
+    fn easings_start_at_zero_and_end_at_one() {
+        for easing in EASINGS {
+            assert!(easing.apply(0.0).abs() < 1e-6, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-6, "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn easings_clamp_progress() {
+        for easing in EASINGS {
+            assert_eq!(easing.apply(-0.5), easing.apply(0.0), "{easing:?}");
+            assert_eq!(easing.apply(1.5), easing.apply(1.0), "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn spring_overshoots_its_target() {
+        assert!((1..10).any(|step| Easing::Spring.apply(step as f32 / 10.0) > 1.0));
+    }
+
+    #[test]
+    fn animated_moves_to_its_target_over_its_duration() {
+        let now = Cell::new(1000);
+        let clock = || now.get();
+        let animated = Animated::new(0, 100, Easing::Linear, &clock);
+
+        animated.animate_to(10);
+        assert_eq!(animated.value(), 0);
+        assert!(animated.is_animating());
+
+        now.set(1050);
+        assert_eq!(animated.value(), 5);
+        animated.mark_clean();
+        assert!(animated.is_dirty());
+
+        now.set(1100);
+        assert_eq!(animated.value(), 10);
+        assert!(!animated.is_animating());
+        animated.mark_clean();
+        assert!(!animated.is_dirty());
+    }
+}
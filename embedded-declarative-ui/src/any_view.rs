@@ -0,0 +1,385 @@
+use crate::{
+    blocking::block_on,
+    draw_target::{DrawError, LayoutDrawTarget},
+    environment::EnvironmentValues,
+    proposed_size::ProposedSize,
+    view::View,
+};
+use core::{
+    marker::PhantomData,
+    mem::{MaybeUninit, align_of, size_of},
+};
+use embedded_graphics::{
+    Pixel,
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Size},
+};
+
+/// Object-safe counterpart of [`View`], implemented for every view.
+///
+/// Views behind `&dyn DynView` draw into a type-erased [`DynDrawTarget`] and are driven
+/// synchronously with [`block_on`]. Wrap them in [`AnyView`] to use them as a view again.
+///
+/// As nothing can be awaited across the erased call, their futures must complete without
+/// waiting: a `body()` awaiting I/O busy-polls the calling task and never yields to the one
+/// it waits on. Only erase views that do not wait, which includes all built-in views.
+pub trait DynView<Color>
+where
+    Color: PixelColor,
+{
+    fn draw_dyn(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, DynDrawTarget<'_, Color>>,
+    );
+
+    fn size_dyn(&self, proposed_size: ProposedSize, environment: &EnvironmentValues<Color>)
+    -> Size;
+}
+
+impl<Color, V> DynView<Color> for V
+where
+    V: View<Color>,
+    Color: PixelColor,
+{
+    fn draw_dyn(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, DynDrawTarget<'_, Color>>,
+    ) {
+        block_on(self.draw(size, environment, draw_target))
+    }
+
+    fn size_dyn(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        block_on(self.size(proposed_size, environment))
+    }
+}
+
+trait PixelSink<Color: PixelColor> {
+    fn draw_pixels(
+        &mut self,
+        pixels: &mut dyn Iterator<Item = Pixel<Color>>,
+    ) -> Result<(), DrawError>;
+}
+
+impl<T, Color> PixelSink<Color> for T
+where
+    T: DrawTarget<Color = Color>,
+    Color: PixelColor,
+{
+    fn draw_pixels(
+        &mut self,
+        pixels: &mut dyn Iterator<Item = Pixel<Color>>,
+    ) -> Result<(), DrawError> {
+        self.draw_iter(pixels).map_err(|_| DrawError::Unknown)
+    }
+}
+
+/// Draw target forwarding to any draw target of the same color, erasing its type.
+pub struct DynDrawTarget<'a, Color>
+where
+    Color: PixelColor,
+{
+    sink: &'a mut dyn PixelSink<Color>,
+    size: Size,
+}
+
+impl<Color> DrawTarget for DynDrawTarget<'_, Color>
+where
+    Color: PixelColor,
+{
+    type Color = Color;
+    type Error = DrawError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.sink.draw_pixels(&mut pixels.into_iter())
+    }
+}
+
+impl<Color> OriginDimensions for DynDrawTarget<'_, Color>
+where
+    Color: PixelColor,
+{
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+/// Type-erased view, so screens of different types can be stored together, e.g. as
+/// `[AnyView<'_, Color>; N]` in a screen manager. The erased view must not wait, see
+/// [`DynView`]. Use [`InlineView`] to own the screens instead of borrowing them.
+pub struct AnyView<'a, Color>
+where
+    Color: PixelColor,
+{
+    view: &'a dyn DynView<Color>,
+}
+
+impl<'a, Color> AnyView<'a, Color>
+where
+    Color: PixelColor,
+{
+    pub fn new(view: &'a dyn DynView<Color>) -> Self {
+        Self { view }
+    }
+}
+
+impl<Color> Clone for AnyView<'_, Color>
+where
+    Color: PixelColor,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Color> Copy for AnyView<'_, Color> where Color: PixelColor {}
+
+impl<Color> View<Color> for AnyView<'_, Color>
+where
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let mut dyn_target = DynDrawTarget {
+            size: draw_target.original_draw_target.size(),
+            sink: &mut *draw_target.original_draw_target,
+        };
+
+        let mut layout_target = LayoutDrawTarget {
+            original_draw_target: &mut dyn_target,
+            offset: draw_target.offset,
+            visitor: match &mut draw_target.visitor {
+                Some(visitor) => Some(&mut **visitor),
+                None => None,
+            },
+            alpha: draw_target.alpha,
+        };
+
+        self.view.draw_dyn(size, environment, &mut layout_target);
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.view.size_dyn(proposed_size, environment)
+    }
+}
+
+#[repr(C, align(8))]
+struct InlineStorage<const SIZE: usize>([MaybeUninit<u8>; SIZE]);
+
+/// Type-erased view stored in a fixed-size buffer of `SIZE` bytes, so screens of different
+/// types can be owned together, e.g. as `[InlineView<Color, 64>; N]`.
+///
+/// Views larger than `SIZE` or aligned to more than 8 bytes fail to compile. The erased view
+/// must not wait, see [`DynView`].
+pub struct InlineView<Color, const SIZE: usize>
+where
+    Color: PixelColor,
+{
+    storage: InlineStorage<SIZE>,
+    as_dyn: fn(*const u8) -> *const dyn DynView<Color>,
+    drop: unsafe fn(*mut u8),
+    _marker: PhantomData<(Color, *const ())>,
+}
+
+fn erase<Color, V>(view: *const u8) -> *const dyn DynView<Color>
+where
+    Color: PixelColor,
+    V: View<Color> + 'static,
+{
+    view.cast::<V>()
+}
+
+unsafe fn drop_view<V>(view: *mut u8) {
+    // SAFETY: only called with the storage `InlineView::new` wrote a `V` into.
+    unsafe { view.cast::<V>().drop_in_place() }
+}
+
+impl<Color, const SIZE: usize> InlineView<Color, SIZE>
+where
+    Color: PixelColor,
+{
+    pub fn new<V>(view: V) -> Self
+    where
+        V: View<Color> + 'static,
+    {
+        const {
+            assert!(
+                size_of::<V>() <= SIZE,
+                "view does not fit into the inline buffer"
+            );
+            assert!(align_of::<V>() <= align_of::<InlineStorage<SIZE>>());
+        }
+
+        let mut storage = InlineStorage([MaybeUninit::uninit(); SIZE]);
+        // SAFETY: the storage is large and aligned enough for `V`, as asserted above.
+        unsafe { storage.0.as_mut_ptr().cast::<V>().write(view) };
+
+        Self {
+            storage,
+            as_dyn: erase::<Color, V>,
+            drop: drop_view::<V>,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Borrows the stored view as an [`AnyView`].
+    pub fn as_any(&self) -> AnyView<'_, Color> {
+        // SAFETY: `as_dyn` belongs to the view written into the storage by `new`.
+        AnyView::new(unsafe { &*(self.as_dyn)(self.storage.0.as_ptr().cast()) })
+    }
+}
+
+impl<Color, const SIZE: usize> Drop for InlineView<Color, SIZE>
+where
+    Color: PixelColor,
+{
+    fn drop(&mut self) {
+        // SAFETY: `drop` belongs to the view written into the storage by `new`, and the
+        // storage is not used again.
+        unsafe { (self.drop)(self.storage.0.as_mut_ptr().cast()) }
+    }
+}
+
+impl<Color, const SIZE: usize> View<Color> for InlineView<Color, SIZE>
+where
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        self.as_any().draw(size, environment, draw_target).await
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.as_any().size(proposed_size, environment).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        alignment::{HorizontalAlignment, VerticalAlignment},
+        blocking::BlockingView,
+        fill::Fill,
+        frame::{Dimension, Frame},
+    };
+    use core::sync::atomic::{AtomicU32, Ordering};
+    use embedded_graphics::{
+        mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::Point, primitives::Rectangle,
+    };
+
+    static DROPS: AtomicU32 = AtomicU32::new(0);
+
+    /// Counts its drops in `DROPS`.
+    struct Counted;
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl View<BinaryColor> for Counted {
+        async fn draw<Target, Error>(
+            &self,
+            _size: Size,
+            _environment: &EnvironmentValues<BinaryColor>,
+            _draw_target: &mut LayoutDrawTarget<'_, Target>,
+        ) where
+            Target: DrawTarget<Color = BinaryColor, Error = Error> + OriginDimensions,
+            Error: 'static,
+        {
+        }
+
+        async fn size(
+            &self,
+            _proposed_size: ProposedSize,
+            _environment: &EnvironmentValues<BinaryColor>,
+        ) -> Size {
+            Size::zero()
+        }
+    }
+
+    /// Bar of `width` in the top leading corner of all space offered.
+    fn bar(width: u32) -> impl View<BinaryColor> + 'static {
+        Frame::new(
+            Dimension::Max,
+            Dimension::Max,
+            HorizontalAlignment::Leading,
+            VerticalAlignment::Top,
+            Frame::new(
+                Dimension::Constant(width),
+                Dimension::Constant(2),
+                HorizontalAlignment::Leading,
+                VerticalAlignment::Top,
+                Fill::new(BinaryColor::On),
+            ),
+        )
+    }
+
+    #[test]
+    fn stored_views_are_dropped_once_after_moves() {
+        let view = InlineView::<BinaryColor, 8>::new(Counted);
+        let moved = view;
+        let screens = [moved, InlineView::new(Counted)];
+        assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+
+        drop(screens);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn size_and_draw_reach_the_stored_view() {
+        let screens: [InlineView<BinaryColor, 128>; 2] =
+            [InlineView::new(bar(3)), InlineView::new(bar(5))];
+        let environment = EnvironmentValues::default();
+
+        for (screen, width) in screens.iter().zip([3, 5]) {
+            assert_eq!(
+                screen.size_blocking(ProposedSize::UNSPECIFIED, &environment),
+                Size::new(width, 2)
+            );
+
+            let mut display = MockDisplay::new();
+            screen.render_blocking(&environment, &mut display);
+
+            let mut expected = MockDisplay::new();
+            _ = expected.fill_solid(
+                &Rectangle::new(Point::zero(), Size::new(width, 2)),
+                BinaryColor::On,
+            );
+            display.assert_eq(&expected);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    draw_target::LayoutDrawTarget, environment::EnvironmentValues, proposed_size::ProposedSize,
+    view::View,
+};
+use core::marker::PhantomData;
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+pub enum ContentMode {
+    /// Largest size with the requested ratio that fits into the available space.
+    Fit,
+    /// Smallest size with the requested ratio that covers the available space.
+    Fill,
+}
+
+/// Constrains `inner_view` to a fixed width-to-height `ratio`.
+pub struct AspectRatio<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    ratio: f32,
+    content_mode: ContentMode,
+    inner_view: InnerView,
+    _marker: PhantomData<Color>,
+}
+
+impl<InnerView, Color> AspectRatio<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(ratio: f32, content_mode: ContentMode, inner_view: InnerView) -> Self {
+        Self {
+            ratio,
+            content_mode,
+            inner_view,
+            _marker: PhantomData,
+        }
+    }
+
+    fn resolve_size(&self, available_size: Size) -> Size {
+        let width_for_height = available_size.height as f32 * self.ratio;
+        let height_for_width = available_size.width as f32 / self.ratio;
+
+        let use_full_height = match self.content_mode {
+            ContentMode::Fit => width_for_height <= available_size.width as f32,
+            ContentMode::Fill => width_for_height >= available_size.width as f32,
+        };
+
+        if use_full_height {
+            Size::new((width_for_height + 0.5) as u32, available_size.height)
+        } else {
+            Size::new(available_size.width, (height_for_width + 0.5) as u32)
+        }
+    }
+}
+
+impl<Color, InnerView> View<Color> for AspectRatio<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        draw_target
+            .draw_child(&self.inner_view, Point::zero(), size, environment)
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        match (proposed_size.width, proposed_size.height) {
+            (Some(width), Some(height)) => self.resolve_size(Size::new(width, height)),
+            (Some(width), None) => Size::new(width, (width as f32 / self.ratio + 0.5) as u32),
+            (None, Some(height)) => Size::new((height as f32 * self.ratio + 0.5) as u32, height),
+            (None, None) => {
+                let ideal_size = self.inner_view.size(proposed_size, environment).await;
+                self.resolve_size(ideal_size)
+            }
+        }
+    }
+}
@@ -1,56 +1,116 @@
-use crate::{draw_target::LayoutDrawTarget, view::View};
-use core::marker::PhantomData;
-use embedded_graphics::{
-    Drawable,
-    prelude::{DrawTarget, OriginDimensions, PixelColor, Size},
-    primitives::{Primitive, PrimitiveStyle, Rectangle},
+use crate::{
+    alignment::{HorizontalAlignment, VerticalAlignment, aligned_offset},
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    fill::Fill,
+    proposed_size::ProposedSize,
+    view::View,
 };
+use core::marker::PhantomData;
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
 
-pub struct Background<InnerView, Color>
+/// Draws `background_view` behind `inner_view`.
+///
+/// The background is proposed the layout size of the inner view and aligned
+/// within it, so it never affects the size of the modified view.
+pub struct Background<InnerView, BackgroundView, Color>
 where
     InnerView: View<Color>,
+    BackgroundView: View<Color>,
     Color: PixelColor,
 {
-    color: Color,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    background_view: BackgroundView,
     inner_view: InnerView,
     _marker: PhantomData<Color>,
 }
 
-impl<InnerView, Color> Background<InnerView, Color>
+impl<InnerView, Color> Background<InnerView, Fill<Color>, Color>
 where
     InnerView: View<Color>,
     Color: PixelColor,
 {
     pub fn new(color: Color, inner_view: InnerView) -> Self {
+        Self::with_view(
+            HorizontalAlignment::Center,
+            VerticalAlignment::Center,
+            Fill::new(color),
+            inner_view,
+        )
+    }
+
+    /// Background with the background color of the environment's theme.
+    pub fn themed(inner_view: InnerView) -> Self {
+        Self::with_view(
+            HorizontalAlignment::Center,
+            VerticalAlignment::Center,
+            Fill::themed(),
+            inner_view,
+        )
+    }
+}
+
+impl<InnerView, BackgroundView, Color> Background<InnerView, BackgroundView, Color>
+where
+    InnerView: View<Color>,
+    BackgroundView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn with_view(
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        background_view: BackgroundView,
+        inner_view: InnerView,
+    ) -> Self {
         Self {
-            color,
+            horizontal_alignment,
+            vertical_alignment,
+            background_view,
             inner_view,
             _marker: PhantomData,
         }
     }
 }
 
-impl<Color, InnerView> View<Color> for Background<InnerView, Color>
+impl<Color, InnerView, BackgroundView> View<Color> for Background<InnerView, BackgroundView, Color>
 where
     InnerView: View<Color>,
+    BackgroundView: View<Color>,
     Color: PixelColor,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
-        let rectangle = Rectangle::new(draw_target.offset, size);
-        let style = PrimitiveStyle::with_fill(self.color);
+        let background_size = self.background_view.size(size.into(), environment).await;
+        let offset = aligned_offset(
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            size,
+            background_size,
+            environment.layout_direction,
+        );
 
-        _ = rectangle
-            .into_styled(style)
-            .draw(draw_target.original_draw_target);
+        draw_target
+            .draw_child(&self.background_view, offset, background_size, environment)
+            .await;
 
-        self.inner_view.draw(size, draw_target).await;
+        draw_target
+            .draw_child(&self.inner_view, Point::zero(), size, environment)
+            .await;
     }
 
-    async fn size(&self, available_size: Size) -> Size {
-        self.inner_view.size(available_size).await
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.inner_view.size(proposed_size, environment).await
     }
 }
@@ -0,0 +1,89 @@
+//! Synchronous entry points for firmware without an async executor.
+//!
+//! None of the built-in views ever wait, so their futures complete on the first poll and
+//! can be driven to completion right here. Composite views awaiting real I/O in `body()`
+//! still work, but are busy-polled until they complete.
+
+use crate::{
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    layout::{self, LayoutNode},
+    proposed_size::ProposedSize,
+    view::View,
+};
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Size};
+
+/// Polls `future` until it completes, e.g. `block_on(focus_state.move_focus(...))`.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// Blocking counterparts of the [`View`] methods, available on every view.
+pub trait BlockingView<Color>: View<Color> {
+    fn draw_blocking<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        block_on(self.draw(size, environment, draw_target))
+    }
+
+    fn size_blocking(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        block_on(self.size(proposed_size, environment))
+    }
+
+    fn layout_blocking(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        nodes: &mut [LayoutNode],
+    ) -> usize
+    where
+        Self: Sized,
+        Color: PixelColor,
+    {
+        block_on(self.layout(size, environment, nodes))
+    }
+
+    /// Draws the view as the root filling `target`, see [`layout::draw_root`].
+    fn render_blocking<Target, Error>(
+        &self,
+        environment: &EnvironmentValues<Color>,
+        target: &mut Target,
+    ) where
+        Self: Sized,
+        Color: PixelColor,
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let size = target.size();
+        block_on(layout::draw_root(
+            self,
+            size,
+            environment,
+            &mut LayoutDrawTarget::new(target),
+        ));
+    }
+}
+
+impl<Color, V> BlockingView<Color> for V where V: View<Color> {}
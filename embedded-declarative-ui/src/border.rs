@@ -1,8 +1,11 @@
-use crate::{draw_target::LayoutDrawTarget, view::View};
+use crate::{
+    draw_target::LayoutDrawTarget, environment::EnvironmentValues, proposed_size::ProposedSize,
+    view::View,
+};
 use core::marker::PhantomData;
 use embedded_graphics::{
     Drawable,
-    prelude::{DrawTarget, OriginDimensions, PixelColor, Size},
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
     primitives::{Primitive, PrimitiveStyle, Rectangle, RoundedRectangle, StrokeAlignment},
 };
 
@@ -11,7 +14,7 @@ where
     InnerView: View<Color>,
     Color: PixelColor,
 {
-    color: Color,
+    color: Option<Color>,
     thickness: u32,
     radius: u32,
     inner_view: InnerView,
@@ -25,7 +28,18 @@ where
 {
     pub fn new(color: Color, thickness: u32, radius: u32, inner_view: InnerView) -> Self {
         Self {
-            color,
+            color: Some(color),
+            thickness,
+            radius,
+            inner_view,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Border with the border color of the environment's theme.
+    pub fn themed(thickness: u32, radius: u32, inner_view: InnerView) -> Self {
+        Self {
+            color: None,
             thickness,
             radius,
             inner_view,
@@ -39,34 +53,49 @@ where
     InnerView: View<Color>,
     Color: PixelColor,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
-        let style = PrimitiveStyle::with_stroke(self.color, self.thickness);
-        let mut style = style;
-        style.stroke_alignment = StrokeAlignment::Inside;
+        let color = self
+            .color
+            .or(environment.theme.as_ref().map(|theme| theme.border));
 
         let rectangle = Rectangle::new(draw_target.offset, size);
 
-        if self.radius > 0 {
-            _ = RoundedRectangle::with_equal_corners(
-                rectangle,
-                Size::new(self.radius, self.radius),
-            )
-            .into_styled(style)
-            .draw(draw_target.original_draw_target);
-        } else {
-            _ = rectangle
+        if let Some(color) = color {
+            let mut style = PrimitiveStyle::with_stroke(color, self.thickness);
+            style.stroke_alignment = StrokeAlignment::Inside;
+
+            if self.radius > 0 {
+                _ = RoundedRectangle::with_equal_corners(
+                    rectangle,
+                    Size::new(self.radius, self.radius),
+                )
                 .into_styled(style)
                 .draw(draw_target.original_draw_target);
+            } else {
+                _ = rectangle
+                    .into_styled(style)
+                    .draw(draw_target.original_draw_target);
+            }
         }
 
-        self.inner_view.draw(size, draw_target).await;
+        draw_target
+            .draw_child(&self.inner_view, Point::zero(), size, environment)
+            .await;
     }
 
-    async fn size(&self, available_size: Size) -> Size {
-        self.inner_view.size(available_size).await
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.inner_view.size(proposed_size, environment).await
     }
 }
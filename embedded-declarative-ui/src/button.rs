@@ -0,0 +1,290 @@
+use crate::{
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    focus::{FocusId, FocusState},
+    proposed_size::{ProposedSize, shrink},
+    theme::Theme,
+    view::View,
+};
+use core::marker::PhantomData;
+use embedded_graphics::{
+    Drawable,
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::{Primitive, PrimitiveStyleBuilder, Rectangle, RoundedRectangle, StrokeAlignment},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ButtonState {
+    Normal,
+    Focused,
+    Pressed,
+    Disabled,
+}
+
+#[derive(Clone, Copy)]
+pub struct ButtonStyle<Color>
+where
+    Color: PixelColor,
+{
+    pub background_color: Color,
+    /// Color of the label, passed to it as the environment's foreground color.
+    pub foreground_color: Color,
+    pub border_color: Color,
+    pub border_thickness: u32,
+    pub corner_radius: u32,
+    /// Space between the border and the label on every edge.
+    pub padding: u32,
+}
+
+#[derive(Clone, Copy)]
+pub struct ButtonStyles<Color>
+where
+    Color: PixelColor,
+{
+    pub normal: ButtonStyle<Color>,
+    pub focused: ButtonStyle<Color>,
+    pub pressed: ButtonStyle<Color>,
+    pub disabled: ButtonStyle<Color>,
+}
+
+impl<Color> ButtonStyles<Color>
+where
+    Color: PixelColor,
+{
+    pub fn from_theme(theme: &Theme<Color>) -> Self {
+        let style = |background_color, foreground_color, border_color| ButtonStyle {
+            background_color,
+            foreground_color,
+            border_color,
+            border_thickness: 1,
+            corner_radius: 2,
+            padding: 2,
+        };
+
+        Self {
+            normal: style(theme.surface, theme.foreground, theme.border),
+            focused: style(theme.accent, theme.background, theme.accent),
+            pressed: style(theme.foreground, theme.background, theme.foreground),
+            disabled: style(theme.background, theme.disabled, theme.disabled),
+        }
+    }
+
+    pub fn style(&self, state: ButtonState) -> &ButtonStyle<Color> {
+        match state {
+            ButtonState::Normal => &self.normal,
+            ButtonState::Focused => &self.focused,
+            ButtonState::Pressed => &self.pressed,
+            ButtonState::Disabled => &self.disabled,
+        }
+    }
+}
+
+/// Focusable and tappable view drawing the label built by `label` with the style of its current state.
+///
+/// The state is read from `focus_state`, where the application marks the button as pressed
+/// while a touch or select press is down. Disabled buttons are neither focusable nor tappable.
+/// Themed buttons derive their styles from the environment's theme and draw only the label without one.
+pub struct Button<'a, Label, LabelView, Action, Color>
+where
+    Label: Fn(ButtonState) -> LabelView,
+    LabelView: View<Color>,
+    Action: Clone + 'static,
+    Color: PixelColor,
+{
+    focus_state: &'a FocusState,
+    id: FocusId,
+    action: Action,
+    enabled: bool,
+    styles: Option<ButtonStyles<Color>>,
+    label: Label,
+    _marker: PhantomData<Color>,
+}
+
+#[allow(dead_code)]
+impl<'a, Label, LabelView, Action, Color> Button<'a, Label, LabelView, Action, Color>
+where
+    Label: Fn(ButtonState) -> LabelView,
+    LabelView: View<Color>,
+    Action: Clone + 'static,
+    Color: PixelColor,
+{
+    pub fn new(
+        focus_state: &'a FocusState,
+        id: FocusId,
+        action: Action,
+        styles: ButtonStyles<Color>,
+        label: Label,
+    ) -> Self {
+        Self {
+            focus_state,
+            id,
+            action,
+            enabled: true,
+            styles: Some(styles),
+            label,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn disabled(
+        focus_state: &'a FocusState,
+        id: FocusId,
+        action: Action,
+        styles: ButtonStyles<Color>,
+        label: Label,
+    ) -> Self {
+        Self {
+            enabled: false,
+            ..Self::new(focus_state, id, action, styles, label)
+        }
+    }
+
+    pub fn themed(focus_state: &'a FocusState, id: FocusId, action: Action, label: Label) -> Self {
+        Self {
+            focus_state,
+            id,
+            action,
+            enabled: true,
+            styles: None,
+            label,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn themed_disabled(
+        focus_state: &'a FocusState,
+        id: FocusId,
+        action: Action,
+        label: Label,
+    ) -> Self {
+        Self {
+            enabled: false,
+            ..Self::themed(focus_state, id, action, label)
+        }
+    }
+
+    pub fn state(&self) -> ButtonState {
+        if !self.enabled {
+            ButtonState::Disabled
+        } else if self.focus_state.is_pressed(self.id) {
+            ButtonState::Pressed
+        } else if self.focus_state.is_focused(self.id) {
+            ButtonState::Focused
+        } else {
+            ButtonState::Normal
+        }
+    }
+
+    fn style(&self, environment: &EnvironmentValues<Color>) -> Option<ButtonStyle<Color>> {
+        let state = self.state();
+
+        match (&self.styles, &environment.theme) {
+            (Some(styles), _) => Some(*styles.style(state)),
+            (None, Some(theme)) => Some(*ButtonStyles::from_theme(theme).style(state)),
+            (None, None) => None,
+        }
+    }
+
+    fn label_environment(
+        &self,
+        style: Option<&ButtonStyle<Color>>,
+        environment: &EnvironmentValues<Color>,
+    ) -> EnvironmentValues<Color> {
+        let mut environment = environment.clone();
+        if let Some(style) = style {
+            environment.foreground_color = Some(style.foreground_color);
+        }
+        environment
+    }
+}
+
+impl<Color, Label, LabelView, Action> View<Color> for Button<'_, Label, LabelView, Action, Color>
+where
+    Label: Fn(ButtonState) -> LabelView,
+    LabelView: View<Color>,
+    Action: Clone + 'static,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let frame = Rectangle::new(draw_target.offset, size);
+
+        if self.enabled
+            && let Some(visitor) = &mut draw_target.visitor
+        {
+            visitor.tappable(frame, &self.action);
+            visitor.focusable(frame, self.id, None);
+            visitor.observed(frame, self.focus_state);
+        }
+
+        let style = self.style(environment);
+
+        if let Some(style) = &style {
+            let mut primitive_style = PrimitiveStyleBuilder::new()
+                .fill_color(style.background_color)
+                .stroke_color(style.border_color)
+                .stroke_width(style.border_thickness)
+                .build();
+            primitive_style.stroke_alignment = StrokeAlignment::Inside;
+
+            _ = RoundedRectangle::with_equal_corners(
+                frame,
+                Size::new(style.corner_radius, style.corner_radius),
+            )
+            .into_styled(primitive_style)
+            .draw(draw_target.original_draw_target);
+        }
+
+        let inset = style.map_or(0, |style| style.border_thickness + style.padding);
+        let content_size = Size::new(
+            size.width.saturating_sub(2 * inset),
+            size.height.saturating_sub(2 * inset),
+        );
+        let label_environment = self.label_environment(style.as_ref(), environment);
+        let label_view = (self.label)(self.state());
+        let label_size = label_view
+            .size(content_size.into(), &label_environment)
+            .await;
+
+        let offset = Point::new(
+            inset as i32 + (content_size.width as i32 - label_size.width as i32) / 2,
+            inset as i32 + (content_size.height as i32 - label_size.height as i32) / 2,
+        );
+
+        draw_target
+            .draw_child(&label_view, offset, label_size, &label_environment)
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        let style = self.style(environment);
+        let inset = style.map_or(0, |style| style.border_thickness + style.padding);
+        let label_environment = self.label_environment(style.as_ref(), environment);
+
+        let label_size = (self.label)(self.state())
+            .size(
+                ProposedSize::new(
+                    shrink(proposed_size.width, 2 * inset),
+                    shrink(proposed_size.height, 2 * inset),
+                ),
+                &label_environment,
+            )
+            .await;
+
+        Size::new(
+            label_size.width.saturating_add(2 * inset),
+            label_size.height.saturating_add(2 * inset),
+        )
+    }
+}
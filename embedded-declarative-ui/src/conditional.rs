@@ -1,4 +1,7 @@
-use crate::{draw_target::LayoutDrawTarget, view::View};
+use crate::{
+    draw_target::LayoutDrawTarget, environment::EnvironmentValues, proposed_size::ProposedSize,
+    view::View,
+};
 use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Size};
 
 pub enum Either<A, B> {
@@ -12,21 +15,29 @@ where
     B: View<Color>,
     Color: PixelColor,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
         match self {
-            Either::Left(view) => view.draw(size, draw_target).await,
-            Either::Right(view) => view.draw(size, draw_target).await,
+            Either::Left(view) => view.draw(size, environment, draw_target).await,
+            Either::Right(view) => view.draw(size, environment, draw_target).await,
         }
     }
 
-    async fn size(&self, available_size: Size) -> Size {
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
         match self {
-            Either::Left(view) => view.size(available_size).await,
-            Either::Right(view) => view.size(available_size).await,
+            Either::Left(view) => view.size(proposed_size, environment).await,
+            Either::Right(view) => view.size(proposed_size, environment).await,
         }
     }
 }
@@ -1,7 +1,16 @@
+use crate::environment::EnvironmentValues;
+use crate::focus::FocusId;
+use crate::layout::LayoutVisitor;
+use crate::proposed_size::ProposedSize;
+use crate::state::Observable;
+use crate::view::View;
+use core::any::Any;
+use core::cell::Cell;
 use embedded_graphics::Pixel;
 use embedded_graphics::draw_target::DrawTarget;
 use embedded_graphics::geometry::{OriginDimensions, Point, Size};
 use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::primitives::Rectangle;
 
 pub enum DrawError {
     Unknown,
@@ -10,6 +19,154 @@ pub enum DrawError {
 pub struct LayoutDrawTarget<'a, T> {
     pub original_draw_target: &'a mut T,
     pub offset: Point,
+    pub visitor: Option<&'a mut dyn LayoutVisitor>,
+    /// Opacity the target applies to the pixels drawn, see [`crate::opacity`].
+    pub alpha: Option<&'a Cell<u8>>,
+}
+
+impl<'a, T> LayoutDrawTarget<'a, T> {
+    pub fn new(original_draw_target: &'a mut T) -> Self {
+        Self {
+            original_draw_target,
+            offset: Point::zero(),
+            visitor: None,
+            alpha: None,
+        }
+    }
+
+    /// Draws `view` with its top left corner at `offset` relative to this target,
+    /// reporting its frame to the layout visitor.
+    pub async fn draw_child<Color, Error, V>(
+        &mut self,
+        view: &V,
+        offset: Point,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+    ) where
+        T: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+        V: View<Color>,
+    {
+        let frame = Rectangle::new(self.offset + offset, size);
+
+        let mut child_target = LayoutDrawTarget {
+            original_draw_target: &mut *self.original_draw_target,
+            offset: frame.top_left,
+            visitor: match &mut self.visitor {
+                Some(visitor) => Some(&mut **visitor),
+                None => None,
+            },
+            alpha: self.alpha,
+        };
+
+        if let Some(visitor) = &mut child_target.visitor {
+            visitor.enter(frame);
+        }
+
+        view.draw(size, environment, &mut child_target).await;
+
+        if let Some(visitor) = child_target.visitor {
+            visitor.exit();
+        }
+    }
+
+    /// Like [`Self::draw_child`], but only draws the pixels of `view` kept by `mask`.
+    pub(crate) async fn draw_child_masked<Color, Error, V>(
+        &mut self,
+        view: &V,
+        offset: Point,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        mask: Mask,
+    ) where
+        T: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Color: PixelColor,
+        Error: 'static,
+        V: View<Color>,
+    {
+        let mut masked_target = MaskedDrawTarget {
+            original_draw_target: &mut *self.original_draw_target,
+            mask,
+        };
+
+        LayoutDrawTarget {
+            original_draw_target: &mut masked_target,
+            offset: self.offset,
+            visitor: match &mut self.visitor {
+                Some(visitor) => Some(&mut **visitor),
+                None => None,
+            },
+            alpha: self.alpha,
+        }
+        .draw_child(view, offset, size, environment)
+        .await;
+    }
+}
+
+/// Draws its content without letting it be tapped or focused, for content that is shown
+/// but can't be interacted with. Its frames and watched states are still reported.
+pub(crate) struct Inert<'a, V>(pub &'a V);
+
+struct InertVisitor<'a> {
+    visitor: &'a mut dyn LayoutVisitor,
+}
+
+impl LayoutVisitor for InertVisitor<'_> {
+    fn enter(&mut self, frame: Rectangle) {
+        self.visitor.enter(frame);
+    }
+
+    fn exit(&mut self) {
+        self.visitor.exit();
+    }
+
+    fn tappable(&mut self, _frame: Rectangle, _action: &dyn Any) {}
+
+    fn focusable(&mut self, _frame: Rectangle, _id: FocusId, _order: Option<u16>) {}
+
+    fn observed(&mut self, frame: Rectangle, state: &dyn Observable) {
+        self.visitor.observed(frame, state);
+    }
+}
+
+impl<Color, V> View<Color> for Inert<'_, V>
+where
+    Color: PixelColor,
+    V: View<Color>,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let mut inert_visitor = draw_target.visitor.as_mut().map(|visitor| InertVisitor {
+            visitor: &mut **visitor,
+        });
+
+        let mut inert_target = LayoutDrawTarget {
+            original_draw_target: &mut *draw_target.original_draw_target,
+            offset: draw_target.offset,
+            visitor: match &mut inert_visitor {
+                Some(visitor) => Some(visitor),
+                None => None,
+            },
+            alpha: draw_target.alpha,
+        };
+
+        self.0.draw(size, environment, &mut inert_target).await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.0.size(proposed_size, environment).await
+    }
 }
 
 impl<'a, T, Error, Color> DrawTarget for LayoutDrawTarget<'a, T>
@@ -42,3 +199,278 @@ where
         self.original_draw_target.size()
     }
 }
+
+/// 4x4 Bayer matrix for ordered dithering.
+const BAYER_MATRIX: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Pixels kept by a [`MaskedDrawTarget`].
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Mask {
+    /// Area outside of which pixels are dropped, in root coordinates.
+    pub clip: Option<Rectangle>,
+    /// Number of pixels out of every 16 kept by ordered dithering, `None` to keep all.
+    pub coverage: Option<u8>,
+    /// Keeps the pixels dropped by `coverage` instead, so the masks with and without
+    /// inversion together cover every pixel exactly once.
+    pub inverted: bool,
+}
+
+impl Mask {
+    pub(crate) fn contains(&self, point: Point) -> bool {
+        let clipped = self.clip.is_some_and(|clip| !clip.contains(point));
+        let dithered = self.coverage.is_some_and(|coverage| {
+            let kept = BAYER_MATRIX[(point.y & 3) as usize][(point.x & 3) as usize] < coverage;
+            kept == self.inverted
+        });
+
+        !clipped && !dithered
+    }
+}
+
+/// Forwards only the pixels kept by `mask`.
+pub(crate) struct MaskedDrawTarget<'a, T> {
+    pub original_draw_target: &'a mut T,
+    pub mask: Mask,
+}
+
+impl<T, Color, Error> DrawTarget for MaskedDrawTarget<'_, T>
+where
+    T: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+    Color: PixelColor,
+{
+    type Color = Color;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mask = self.mask;
+        self.original_draw_target.draw_iter(
+            pixels
+                .into_iter()
+                .filter(|Pixel(point, _)| mask.contains(*point)),
+        )
+    }
+}
+
+impl<T> OriginDimensions for MaskedDrawTarget<'_, T>
+where
+    T: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.original_draw_target.size()
+    }
+}
+
+/// Clockwise rotation of the view tree relative to the panel.
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DisplayRotation {
+    #[default]
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+/// Orientation of the logical coordinate space on the panel, mirroring horizontally before rotating.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DisplayTransform {
+    pub rotation: DisplayRotation,
+    pub mirrored: bool,
+}
+
+impl DisplayTransform {
+    pub fn new(rotation: DisplayRotation, mirrored: bool) -> Self {
+        Self { rotation, mirrored }
+    }
+
+    /// Size of the logical coordinate space on a panel of `physical_size`.
+    pub fn logical_size(&self, physical_size: Size) -> Size {
+        match self.rotation {
+            DisplayRotation::Deg0 | DisplayRotation::Deg180 => physical_size,
+            DisplayRotation::Deg90 | DisplayRotation::Deg270 => {
+                Size::new(physical_size.height, physical_size.width)
+            }
+        }
+    }
+
+    /// Maps `point` in logical coordinates to the panel of `physical_size`.
+    pub fn apply(&self, point: Point, physical_size: Size) -> Point {
+        let logical_size = self.logical_size(physical_size);
+        let (width, height) = (physical_size.width as i32, physical_size.height as i32);
+
+        let x = match self.mirrored {
+            true => logical_size.width as i32 - 1 - point.x,
+            false => point.x,
+        };
+        let y = point.y;
+
+        match self.rotation {
+            DisplayRotation::Deg0 => Point::new(x, y),
+            DisplayRotation::Deg90 => Point::new(width - 1 - y, x),
+            DisplayRotation::Deg180 => Point::new(width - 1 - x, height - 1 - y),
+            DisplayRotation::Deg270 => Point::new(y, height - 1 - x),
+        }
+    }
+
+    /// Maps `point` on the panel of `physical_size` back to logical coordinates, e.g. to
+    /// pass touch input to [`hit_test`](crate::on_tap::hit_test). Inverse of [`Self::apply`].
+    pub fn to_logical(&self, point: Point, physical_size: Size) -> Point {
+        let logical_size = self.logical_size(physical_size);
+        let (width, height) = (physical_size.width as i32, physical_size.height as i32);
+
+        let (x, y) = match self.rotation {
+            DisplayRotation::Deg0 => (point.x, point.y),
+            DisplayRotation::Deg90 => (point.y, width - 1 - point.x),
+            DisplayRotation::Deg180 => (width - 1 - point.x, height - 1 - point.y),
+            DisplayRotation::Deg270 => (height - 1 - point.y, point.x),
+        };
+
+        match self.mirrored {
+            true => Point::new(logical_size.width as i32 - 1 - x, y),
+            false => Point::new(x, y),
+        }
+    }
+}
+
+/// Rotates and mirrors pixels on their way to the panel driver, so the view tree can
+/// lay out in logical coordinates regardless of how the panel is mounted.
+///
+/// Wrap the driver before handing it to [`LayoutDrawTarget::new`].
+pub struct TransformedDrawTarget<'a, T> {
+    pub original_draw_target: &'a mut T,
+    pub transform: DisplayTransform,
+}
+
+impl<'a, T> TransformedDrawTarget<'a, T>
+where
+    T: OriginDimensions,
+{
+    pub fn new(original_draw_target: &'a mut T, transform: DisplayTransform) -> Self {
+        Self {
+            original_draw_target,
+            transform,
+        }
+    }
+
+    /// Maps `point` on the panel to logical coordinates, see [`DisplayTransform::to_logical`].
+    pub fn to_logical(&self, point: Point) -> Point {
+        self.transform
+            .to_logical(point, self.original_draw_target.size())
+    }
+}
+
+impl<'a, T, Error, Color> DrawTarget for TransformedDrawTarget<'a, T>
+where
+    T: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+    Color: PixelColor,
+{
+    type Color = Color;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let physical_size = self.original_draw_target.size();
+        let transform = self.transform;
+
+        self.original_draw_target.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(transform.apply(point, physical_size), color)),
+        )
+    }
+}
+
+impl<'a, T> OriginDimensions for TransformedDrawTarget<'a, T>
+where
+    T: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.transform
+            .logical_size(self.original_draw_target.size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::primitives::PointsIter;
+
+    const PHYSICAL_SIZE: Size = Size::new(5, 3);
+
+    fn transforms() -> impl Iterator<Item = DisplayTransform> {
+        [
+            DisplayRotation::Deg0,
+            DisplayRotation::Deg90,
+            DisplayRotation::Deg180,
+            DisplayRotation::Deg270,
+        ]
+        .into_iter()
+        .flat_map(|rotation| {
+            [false, true]
+                .into_iter()
+                .map(move |mirrored| DisplayTransform::new(rotation, mirrored))
+        })
+    }
+
+    #[test]
+    fn logical_origin_maps_to_the_expected_corner() {
+        let expected = [
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 0),
+            Point::new(4, 2),
+            Point::new(4, 2),
+            Point::new(0, 2),
+            Point::new(0, 2),
+            Point::new(0, 0),
+        ];
+
+        for (transform, expected) in transforms().zip(expected) {
+            assert_eq!(
+                transform.apply(Point::zero(), PHYSICAL_SIZE),
+                expected,
+                "{transform:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn logical_size_swaps_axes_for_quarter_turns() {
+        for transform in transforms() {
+            let expected = match transform.rotation {
+                DisplayRotation::Deg0 | DisplayRotation::Deg180 => PHYSICAL_SIZE,
+                DisplayRotation::Deg90 | DisplayRotation::Deg270 => Size::new(3, 5),
+            };
+            assert_eq!(transform.logical_size(PHYSICAL_SIZE), expected);
+        }
+    }
+
+    #[test]
+    fn every_logical_point_maps_onto_the_panel_and_back() {
+        for transform in transforms() {
+            let logical = Rectangle::new(Point::zero(), transform.logical_size(PHYSICAL_SIZE));
+            let physical = Rectangle::new(Point::zero(), PHYSICAL_SIZE);
+
+            for point in logical.points() {
+                let mapped = transform.apply(point, PHYSICAL_SIZE);
+                assert!(physical.contains(mapped), "{transform:?} {point:?}");
+                assert_eq!(
+                    transform.to_logical(mapped, PHYSICAL_SIZE),
+                    point,
+                    "{transform:?}"
+                );
+            }
+
+            for point in physical.points() {
+                let logical_point = transform.to_logical(point, PHYSICAL_SIZE);
+                assert!(logical.contains(logical_point), "{transform:?} {point:?}");
+                assert_eq!(transform.apply(logical_point, PHYSICAL_SIZE), point);
+            }
+        }
+    }
+}
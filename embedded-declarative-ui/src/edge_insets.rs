@@ -0,0 +1,110 @@
+use crate::environment::LayoutDirection;
+use core::ops::{Add, Sub};
+
+/// Insets from the edges of a rectangle, with the horizontal edges relative to the layout direction.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct EdgeInsets {
+    pub top: u32,
+    pub leading: u32,
+    pub bottom: u32,
+    pub trailing: u32,
+}
+
+#[allow(dead_code)]
+impl EdgeInsets {
+    pub const ZERO: EdgeInsets = EdgeInsets::all(0);
+
+    pub const fn new(top: u32, leading: u32, bottom: u32, trailing: u32) -> Self {
+        Self {
+            top,
+            leading,
+            bottom,
+            trailing,
+        }
+    }
+
+    pub const fn all(all: u32) -> Self {
+        Self::new(all, all, all, all)
+    }
+
+    pub const fn symmetric(horizontal: u32, vertical: u32) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    pub const fn horizontal(horizontal: u32) -> Self {
+        Self::symmetric(horizontal, 0)
+    }
+
+    pub const fn vertical(vertical: u32) -> Self {
+        Self::symmetric(0, vertical)
+    }
+
+    pub const fn top(top: u32) -> Self {
+        Self::new(top, 0, 0, 0)
+    }
+
+    pub const fn leading(leading: u32) -> Self {
+        Self::new(0, leading, 0, 0)
+    }
+
+    pub const fn bottom(bottom: u32) -> Self {
+        Self::new(0, 0, bottom, 0)
+    }
+
+    pub const fn trailing(trailing: u32) -> Self {
+        Self::new(0, 0, 0, trailing)
+    }
+
+    /// Sum of the leading and trailing insets.
+    pub const fn horizontal_total(&self) -> u32 {
+        self.leading + self.trailing
+    }
+
+    /// Sum of the top and bottom insets.
+    pub const fn vertical_total(&self) -> u32 {
+        self.top + self.bottom
+    }
+
+    /// Inset from the left edge once `layout_direction` is applied.
+    pub fn left(&self, layout_direction: LayoutDirection) -> u32 {
+        match layout_direction {
+            LayoutDirection::LeftToRight => self.leading,
+            LayoutDirection::RightToLeft => self.trailing,
+        }
+    }
+
+    /// Inset from the right edge once `layout_direction` is applied.
+    pub fn right(&self, layout_direction: LayoutDirection) -> u32 {
+        match layout_direction {
+            LayoutDirection::LeftToRight => self.trailing,
+            LayoutDirection::RightToLeft => self.leading,
+        }
+    }
+}
+
+impl Add for EdgeInsets {
+    type Output = EdgeInsets;
+
+    fn add(self, other: EdgeInsets) -> EdgeInsets {
+        EdgeInsets::new(
+            self.top + other.top,
+            self.leading + other.leading,
+            self.bottom + other.bottom,
+            self.trailing + other.trailing,
+        )
+    }
+}
+
+/// Saturates at zero on every edge.
+impl Sub for EdgeInsets {
+    type Output = EdgeInsets;
+
+    fn sub(self, other: EdgeInsets) -> EdgeInsets {
+        EdgeInsets::new(
+            self.top.saturating_sub(other.top),
+            self.leading.saturating_sub(other.leading),
+            self.bottom.saturating_sub(other.bottom),
+            self.trailing.saturating_sub(other.trailing),
+        )
+    }
+}
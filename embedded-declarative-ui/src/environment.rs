@@ -0,0 +1,126 @@
+use crate::{draw_target::LayoutDrawTarget, proposed_size::ProposedSize, theme::Theme, view::View};
+use core::marker::PhantomData;
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
+use u8g2_fonts::FontRenderer;
+
+/// Direction in which horizontal content flows. Right-to-left reverses `HStack` and mirrors
+/// leading/trailing alignments, padding and text placement.
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+/// Values passed down the view tree during `size()` and `draw()`.
+///
+/// Unset values fall back to the explicit arguments of the views reading them.
+#[derive(Clone)]
+pub struct EnvironmentValues<Color> {
+    pub foreground_color: Option<Color>,
+    pub accent_color: Option<Color>,
+    pub font: Option<FontRenderer>,
+    pub layout_direction: LayoutDirection,
+    pub theme: Option<Theme<Color>>,
+}
+
+impl<Color> Default for EnvironmentValues<Color> {
+    fn default() -> Self {
+        Self {
+            foreground_color: None,
+            accent_color: None,
+            font: None,
+            layout_direction: LayoutDirection::default(),
+            theme: None,
+        }
+    }
+}
+
+impl<Color> EnvironmentValues<Color>
+where
+    Color: PixelColor,
+{
+    pub fn with_theme(theme: Theme<Color>) -> Self {
+        let mut environment = Self::default();
+        environment.set_theme(theme);
+        environment
+    }
+
+    /// Sets `theme` along with the foreground color, accent color and font it defines.
+    pub fn set_theme(&mut self, theme: Theme<Color>) {
+        self.foreground_color = Some(theme.foreground);
+        self.accent_color = Some(theme.accent);
+        self.font = Some(theme.font.clone());
+        self.theme = Some(theme);
+    }
+}
+
+/// Modifies the environment values seen by `inner_view` and its descendants.
+pub struct Environment<InnerView, Modify, Color>
+where
+    InnerView: View<Color>,
+    Modify: Fn(&mut EnvironmentValues<Color>),
+    Color: PixelColor,
+{
+    modify: Modify,
+    inner_view: InnerView,
+    _marker: PhantomData<Color>,
+}
+
+impl<InnerView, Modify, Color> Environment<InnerView, Modify, Color>
+where
+    InnerView: View<Color>,
+    Modify: Fn(&mut EnvironmentValues<Color>),
+    Color: PixelColor,
+{
+    pub fn new(modify: Modify, inner_view: InnerView) -> Self {
+        Self {
+            modify,
+            inner_view,
+            _marker: PhantomData,
+        }
+    }
+
+    fn modified(&self, environment: &EnvironmentValues<Color>) -> EnvironmentValues<Color> {
+        let mut environment = environment.clone();
+        (self.modify)(&mut environment);
+        environment
+    }
+}
+
+impl<Color, InnerView, Modify> View<Color> for Environment<InnerView, Modify, Color>
+where
+    InnerView: View<Color>,
+    Modify: Fn(&mut EnvironmentValues<Color>),
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        draw_target
+            .draw_child(
+                &self.inner_view,
+                Point::zero(),
+                size,
+                &self.modified(environment),
+            )
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.inner_view
+            .size(proposed_size, &self.modified(environment))
+            .await
+    }
+}
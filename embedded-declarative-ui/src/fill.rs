@@ -0,0 +1,67 @@
+use crate::{
+    draw_target::LayoutDrawTarget, environment::EnvironmentValues, proposed_size::ProposedSize,
+    view::View,
+};
+use embedded_graphics::{
+    Drawable,
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Size},
+    primitives::{Primitive, PrimitiveStyle, Rectangle},
+};
+
+pub struct Fill<Color>
+where
+    Color: PixelColor,
+{
+    color: Option<Color>,
+}
+
+impl<Color> Fill<Color>
+where
+    Color: PixelColor,
+{
+    pub fn new(color: Color) -> Self {
+        Self { color: Some(color) }
+    }
+
+    /// Fills with the background color of the environment's theme.
+    pub fn themed() -> Self {
+        Self { color: None }
+    }
+}
+
+impl<Color> View<Color> for Fill<Color>
+where
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let color = self
+            .color
+            .or(environment.theme.as_ref().map(|theme| theme.background));
+        let Some(color) = color else {
+            return;
+        };
+
+        let rectangle = Rectangle::new(draw_target.offset, size);
+        let style = PrimitiveStyle::with_fill(color);
+
+        _ = rectangle
+            .into_styled(style)
+            .draw(draw_target.original_draw_target);
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        _environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        proposed_size.replacing_unbounded(Size::zero())
+    }
+}
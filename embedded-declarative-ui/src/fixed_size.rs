@@ -0,0 +1,88 @@
+use crate::{
+    draw_target::LayoutDrawTarget, environment::EnvironmentValues, proposed_size::ProposedSize,
+    view::View,
+};
+use core::marker::PhantomData;
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
+
+/// Measures `inner_view` with an unspecified proposal along the fixed axes,
+/// so it keeps its ideal size there regardless of the space offered by the parent.
+pub struct FixedSize<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    horizontal: bool,
+    vertical: bool,
+    inner_view: InnerView,
+    _marker: PhantomData<Color>,
+}
+
+#[allow(dead_code)]
+impl<InnerView, Color> FixedSize<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(horizontal: bool, vertical: bool, inner_view: InnerView) -> Self {
+        Self {
+            horizontal,
+            vertical,
+            inner_view,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn horizontal(inner_view: InnerView) -> Self {
+        Self::new(true, false, inner_view)
+    }
+
+    pub fn vertical(inner_view: InnerView) -> Self {
+        Self::new(false, true, inner_view)
+    }
+
+    pub fn both(inner_view: InnerView) -> Self {
+        Self::new(true, true, inner_view)
+    }
+}
+
+impl<Color, InnerView> View<Color> for FixedSize<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        draw_target
+            .draw_child(&self.inner_view, Point::zero(), size, environment)
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        let proposed_size = ProposedSize::new(
+            if self.horizontal {
+                None
+            } else {
+                proposed_size.width
+            },
+            if self.vertical {
+                None
+            } else {
+                proposed_size.height
+            },
+        );
+
+        self.inner_view.size(proposed_size, environment).await
+    }
+}
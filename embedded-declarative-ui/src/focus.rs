@@ -0,0 +1,460 @@
+use crate::{
+    draw_target::LayoutDrawTarget,
+    environment::{EnvironmentValues, LayoutDirection},
+    layout::{self, LayoutVisitor},
+    proposed_size::ProposedSize,
+    state::Observable,
+    view::View,
+};
+use core::{cell::Cell, marker::PhantomData};
+use embedded_graphics::{
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FocusId(pub u16);
+
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FocusDirection {
+    Next,
+    Previous,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Currently focused and pressed views, shared between the application and the views reading it.
+///
+/// [`Focusable`] views and buttons report it as their state, so they are redrawn after it changed.
+#[derive(Default)]
+pub struct FocusState {
+    focused: Cell<Option<FocusId>>,
+    pressed: Cell<Option<FocusId>>,
+    dirty: Cell<bool>,
+}
+
+impl FocusState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn focused(&self) -> Option<FocusId> {
+        self.focused.get()
+    }
+
+    pub fn is_focused(&self, id: FocusId) -> bool {
+        self.focused.get() == Some(id)
+    }
+
+    pub fn set_focused(&self, id: Option<FocusId>) {
+        if self.focused.replace(id) != id {
+            self.dirty.set(true);
+        }
+    }
+
+    pub fn pressed(&self) -> Option<FocusId> {
+        self.pressed.get()
+    }
+
+    pub fn is_pressed(&self, id: FocusId) -> bool {
+        self.pressed.get() == Some(id)
+    }
+
+    /// Marks the view with `id` as pressed, e.g. while a touch is down on it or select is held.
+    pub fn set_pressed(&self, id: Option<FocusId>) {
+        if self.pressed.replace(id) != id {
+            self.dirty.set(true);
+        }
+    }
+
+    /// Moves the focus among the [`Focusable`] views of `view` placed into `size` within `environment` and returns the new focus.
+    ///
+    /// `Next` and `Previous` follow the explicit focus order first, then the remaining views in
+    /// reading order of their frames (right to left for right-to-left layouts), wrapping around at the ends. Directional moves pick the
+    /// closest view in that direction and keep the focus if there is none.
+    pub async fn move_focus<Color, V>(
+        &self,
+        view: &V,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        direction: FocusDirection,
+    ) -> Option<FocusId>
+    where
+        Color: PixelColor,
+        V: View<Color>,
+    {
+        let mut finder = CurrentFinder {
+            id: self.focused.get(),
+            layout_direction: environment.layout_direction,
+            current: None,
+        };
+        layout::visit(view, size, environment, &mut finder).await;
+
+        let mut selector = FocusSelector {
+            current: finder.current,
+            layout_direction: environment.layout_direction,
+            direction,
+            best: None,
+            wrap: None,
+        };
+        layout::visit(view, size, environment, &mut selector).await;
+
+        if let Some((_, id)) = selector.best.or(selector.wrap) {
+            self.set_focused(Some(id));
+        }
+
+        self.focused.get()
+    }
+}
+
+impl Observable for FocusState {
+    fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    fn mark_clean(&self) {
+        self.dirty.set(false);
+    }
+}
+
+/// Position of a focusable view in the sequential focus order.
+type FocusKey = (bool, Option<u16>, i32, i32, u16);
+
+#[derive(Clone, Copy)]
+struct FocusCandidate {
+    frame: Rectangle,
+    key: FocusKey,
+}
+
+impl FocusCandidate {
+    fn new(
+        frame: Rectangle,
+        id: FocusId,
+        order: Option<u16>,
+        layout_direction: LayoutDirection,
+    ) -> Self {
+        let leading_x = match layout_direction {
+            LayoutDirection::LeftToRight => frame.top_left.x,
+            LayoutDirection::RightToLeft => -(frame.top_left.x + frame.size.width as i32),
+        };
+
+        Self {
+            frame,
+            key: (order.is_none(), order, frame.top_left.y, leading_x, id.0),
+        }
+    }
+}
+
+struct CurrentFinder {
+    id: Option<FocusId>,
+    layout_direction: LayoutDirection,
+    current: Option<FocusCandidate>,
+}
+
+impl LayoutVisitor for CurrentFinder {
+    fn enter(&mut self, _frame: Rectangle) {}
+
+    fn exit(&mut self) {}
+
+    fn focusable(&mut self, frame: Rectangle, id: FocusId, order: Option<u16>) {
+        if self.id == Some(id) {
+            self.current = Some(FocusCandidate::new(frame, id, order, self.layout_direction));
+        }
+    }
+}
+
+struct FocusSelector {
+    current: Option<FocusCandidate>,
+    layout_direction: LayoutDirection,
+    direction: FocusDirection,
+    /// Best candidate by score, lower is better.
+    best: Option<(FocusKey, FocusId)>,
+    /// Candidate used when no view follows the current one in sequential order.
+    wrap: Option<(FocusKey, FocusId)>,
+}
+
+impl FocusSelector {
+    fn consider(slot: &mut Option<(FocusKey, FocusId)>, score: FocusKey, id: FocusId) {
+        if slot.is_none_or(|(best_score, _)| score < best_score) {
+            *slot = Some((score, id));
+        }
+    }
+}
+
+impl LayoutVisitor for FocusSelector {
+    fn enter(&mut self, _frame: Rectangle) {}
+
+    fn exit(&mut self) {}
+
+    fn focusable(&mut self, frame: Rectangle, id: FocusId, order: Option<u16>) {
+        let candidate = FocusCandidate::new(frame, id, order, self.layout_direction);
+        let inverted = |key: FocusKey| {
+            (
+                !key.0,
+                key.1.map(|order| u16::MAX - order),
+                -key.2,
+                -key.3,
+                u16::MAX - key.4,
+            )
+        };
+
+        let Some(current) = self.current else {
+            let score = match self.direction {
+                FocusDirection::Previous => inverted(candidate.key),
+                _ => candidate.key,
+            };
+            Self::consider(&mut self.best, score, id);
+            return;
+        };
+
+        if candidate.key == current.key {
+            return;
+        }
+
+        match self.direction {
+            FocusDirection::Next => {
+                if candidate.key > current.key {
+                    Self::consider(&mut self.best, candidate.key, id);
+                } else {
+                    Self::consider(&mut self.wrap, candidate.key, id);
+                }
+            }
+            FocusDirection::Previous => {
+                if candidate.key < current.key {
+                    Self::consider(&mut self.best, inverted(candidate.key), id);
+                } else {
+                    Self::consider(&mut self.wrap, inverted(candidate.key), id);
+                }
+            }
+            direction => {
+                let from = current.frame.center();
+                let to = candidate.frame.center();
+
+                let (primary, secondary) = match direction {
+                    FocusDirection::Up => (from.y - to.y, to.x - from.x),
+                    FocusDirection::Down => (to.y - from.y, to.x - from.x),
+                    FocusDirection::Left => (from.x - to.x, to.y - from.y),
+                    _ => (to.x - from.x, to.y - from.y),
+                };
+
+                if primary > 0 {
+                    let distance = primary + 2 * secondary.abs();
+                    Self::consider(&mut self.best, (false, None, distance, 0, id.0), id);
+                }
+            }
+        }
+    }
+}
+
+/// Marks the content built by `content` as focusable with `id`.
+///
+/// `content` is called with whether the view currently has the focus, so it can be styled accordingly.
+pub struct Focusable<'a, Content, ContentView, Color>
+where
+    Content: Fn(bool) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    focus_state: &'a FocusState,
+    id: FocusId,
+    order: Option<u16>,
+    content: Content,
+    _marker: PhantomData<Color>,
+}
+
+impl<'a, Content, ContentView, Color> Focusable<'a, Content, ContentView, Color>
+where
+    Content: Fn(bool) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(focus_state: &'a FocusState, id: FocusId, content: Content) -> Self {
+        Self {
+            focus_state,
+            id,
+            order: None,
+            content,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Places the view at `order` in the sequential focus order, ahead of views ordered by layout position.
+    pub fn with_order(
+        focus_state: &'a FocusState,
+        id: FocusId,
+        order: u16,
+        content: Content,
+    ) -> Self {
+        Self {
+            order: Some(order),
+            ..Self::new(focus_state, id, content)
+        }
+    }
+
+    fn content_view(&self) -> ContentView {
+        (self.content)(self.focus_state.is_focused(self.id))
+    }
+}
+
+impl<Color, Content, ContentView> View<Color> for Focusable<'_, Content, ContentView, Color>
+where
+    Content: Fn(bool) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        if let Some(visitor) = &mut draw_target.visitor {
+            let frame = Rectangle::new(draw_target.offset, size);
+            visitor.focusable(frame, self.id, self.order);
+            visitor.observed(frame, self.focus_state);
+        }
+
+        draw_target
+            .draw_child(&self.content_view(), Point::zero(), size, environment)
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.content_view().size(proposed_size, environment).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        alignment::{HorizontalAlignment, VerticalAlignment},
+        blocking::block_on,
+        fill::Fill,
+        frame::{Dimension, Frame},
+        hstack::HStack,
+        vstack::VStack,
+    };
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    const SIZE: Size = Size::new(20, 20);
+
+    fn cell(focus_state: &FocusState, id: u16) -> impl View<BinaryColor> + '_ {
+        Focusable::new(focus_state, FocusId(id), |_| {
+            Frame::new(
+                Dimension::Constant(4),
+                Dimension::Constant(4),
+                HorizontalAlignment::Left,
+                VerticalAlignment::Top,
+                Fill::new(BinaryColor::On),
+            )
+        })
+    }
+
+    /// Two rows of two cells, with IDs in reverse reading order.
+    fn grid(focus_state: &FocusState) -> impl View<BinaryColor> + '_ {
+        VStack::new(
+            2,
+            HorizontalAlignment::Left,
+            (
+                HStack::new(
+                    2,
+                    VerticalAlignment::Top,
+                    (cell(focus_state, 4), cell(focus_state, 3)),
+                ),
+                HStack::new(
+                    2,
+                    VerticalAlignment::Top,
+                    (cell(focus_state, 2), cell(focus_state, 1)),
+                ),
+            ),
+        )
+    }
+
+    fn moves(focus_state: &FocusState, direction: FocusDirection, count: usize) -> [u16; 5] {
+        let view = grid(focus_state);
+        let environment = EnvironmentValues::default();
+        let mut ids = [0; 5];
+
+        for id in ids.iter_mut().take(count) {
+            let focused = block_on(focus_state.move_focus(&view, SIZE, &environment, direction));
+            *id = focused.map_or(0, |focused| focused.0);
+        }
+
+        ids
+    }
+
+    #[test]
+    fn next_follows_reading_order_across_rows_and_wraps() {
+        let focus_state = FocusState::new();
+
+        assert_eq!(
+            moves(&focus_state, FocusDirection::Next, 5),
+            [4, 3, 2, 1, 4]
+        );
+    }
+
+    #[test]
+    fn previous_follows_reverse_reading_order_and_wraps() {
+        let focus_state = FocusState::new();
+
+        assert_eq!(
+            moves(&focus_state, FocusDirection::Previous, 5),
+            [1, 2, 3, 4, 1]
+        );
+    }
+
+    #[test]
+    fn next_and_previous_are_inverse() {
+        let focus_state = FocusState::new();
+        focus_state.set_focused(Some(FocusId(3)));
+
+        assert_eq!(moves(&focus_state, FocusDirection::Next, 1)[0], 2);
+        assert_eq!(moves(&focus_state, FocusDirection::Previous, 1)[0], 3);
+    }
+
+    #[test]
+    fn explicit_order_comes_first() {
+        let focus_state = FocusState::new();
+        let view = HStack::new(
+            2,
+            VerticalAlignment::Top,
+            (
+                cell(&focus_state, 1),
+                Focusable::with_order(&focus_state, FocusId(2), 0, |_| Fill::new(BinaryColor::On)),
+            ),
+        );
+        let environment = EnvironmentValues::default();
+
+        let focused =
+            block_on(focus_state.move_focus(&view, SIZE, &environment, FocusDirection::Next));
+
+        assert_eq!(focused, Some(FocusId(2)));
+    }
+
+    #[test]
+    fn empty_focus_list_keeps_focus() {
+        let focus_state = FocusState::new();
+        let view = Fill::new(BinaryColor::On);
+        let environment = EnvironmentValues::default();
+
+        let focused =
+            block_on(focus_state.move_focus(&view, SIZE, &environment, FocusDirection::Next));
+        assert_eq!(focused, None);
+
+        focus_state.set_focused(Some(FocusId(7)));
+        let focused =
+            block_on(focus_state.move_focus(&view, SIZE, &environment, FocusDirection::Previous));
+        assert_eq!(focused, Some(FocusId(7)));
+    }
+}
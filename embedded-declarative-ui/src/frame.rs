@@ -1,16 +1,59 @@
 use crate::{
-    alignment::{HorizontalAlignment, VerticalAlignment},
+    alignment::{HorizontalAlignment, VerticalAlignment, aligned_offset},
     draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    proposed_size::ProposedSize,
     view::View,
 };
 use core::marker::PhantomData;
-use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Size};
 
 pub enum Dimension {
+    /// Fits the inner view into the proposed size.
     Min,
+    /// Takes the proposed size, or fits the inner view if unspecified.
     Max,
+    /// Takes the ideal size of the inner view, regardless of the proposed size.
+    Ideal,
     Constant(u32),
     Fraction(f32),
+    /// Fits the inner view, clamped between `min` and `max`.
+    ///
+    /// The inner view is proposed the proposed size limited to the same bounds,
+    /// or `ideal` if the proposed size is unspecified.
+    Flexible {
+        min: Option<Length>,
+        ideal: Option<u32>,
+        max: Option<Length>,
+    },
+}
+
+#[derive(Clone, Copy)]
+pub enum Length {
+    Constant(u32),
+    Fraction(f32),
+}
+
+impl Length {
+    /// Fractions of an unspecified proposal don't resolve to a length.
+    fn resolve(self, proposed: Option<u32>) -> Option<u32> {
+        match self {
+            Length::Constant(c) => Some(c),
+            Length::Fraction(f) => proposed.map(|proposed| (proposed as f32 * f) as u32),
+        }
+    }
+}
+
+fn clamp(value: u32, min: Option<Length>, max: Option<Length>, proposed: Option<u32>) -> u32 {
+    let value = match max.and_then(|max| max.resolve(proposed)) {
+        Some(max) => value.min(max),
+        None => value,
+    };
+
+    match min.and_then(|min| min.resolve(proposed)) {
+        Some(min) => value.max(min),
+        None => value,
+    }
 }
 
 pub struct Frame<InnerView, Color>
@@ -48,24 +91,74 @@ where
         }
     }
 
-    async fn resolve_size(&self, available_size: Size) -> Size {
-        let w = match self.width {
-            Dimension::Max => available_size.width,
-            Dimension::Constant(c) => c,
-            Dimension::Min => self.inner_view.size(available_size).await.width,
-            Dimension::Fraction(f) => (available_size.width as f32 * f) as u32,
+    async fn resolve_size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        let w = match (&self.width, proposed_size.width) {
+            (Dimension::Constant(c), _) => *c,
+            (Dimension::Max, Some(width)) => width,
+            (Dimension::Fraction(f), Some(width)) => (width as f32 * f) as u32,
+            (Dimension::Ideal, _) => {
+                self.inner_view
+                    .size(ProposedSize::new(None, proposed_size.height), environment)
+                    .await
+                    .width
+            }
+            (Dimension::Min | Dimension::Max | Dimension::Fraction(_), _) => {
+                self.inner_view.size(proposed_size, environment).await.width
+            }
+            (&Dimension::Flexible { min, ideal, max }, width) => {
+                let content_proposed_width = match width {
+                    Some(width) => Some(clamp(width, min, max, Some(width))),
+                    None => ideal.map(|ideal| clamp(ideal, min, max, None)),
+                };
+                let content_width = self
+                    .inner_view
+                    .size(
+                        ProposedSize::new(content_proposed_width, proposed_size.height),
+                        environment,
+                    )
+                    .await
+                    .width;
+
+                clamp(content_width, min, max, width)
+            }
         };
 
-        let h = match self.height {
-            Dimension::Max => available_size.height,
-            Dimension::Constant(c) => c,
-            Dimension::Min => {
+        let h = match (&self.height, proposed_size.height) {
+            (Dimension::Constant(c), _) => *c,
+            (Dimension::Max, Some(height)) => height,
+            (Dimension::Fraction(f), Some(height)) => (height as f32 * f) as u32,
+            (Dimension::Ideal, _) => {
+                self.inner_view
+                    .size(ProposedSize::new(Some(w), None), environment)
+                    .await
+                    .height
+            }
+            (Dimension::Min | Dimension::Max | Dimension::Fraction(_), height) => {
                 self.inner_view
-                    .size(Size::new(w, available_size.height))
+                    .size(ProposedSize::new(Some(w), height), environment)
                     .await
                     .height
             }
-            Dimension::Fraction(f) => (available_size.height as f32 * f) as u32,
+            (&Dimension::Flexible { min, ideal, max }, height) => {
+                let content_proposed_height = match height {
+                    Some(height) => Some(clamp(height, min, max, Some(height))),
+                    None => ideal.map(|ideal| clamp(ideal, min, max, None)),
+                };
+                let content_height = self
+                    .inner_view
+                    .size(
+                        ProposedSize::new(Some(w), content_proposed_height),
+                        environment,
+                    )
+                    .await
+                    .height;
+
+                clamp(content_height, min, max, height)
+            }
         };
 
         Size::new(w, h)
@@ -77,39 +170,35 @@ where
     InnerView: View<Color>,
     Color: PixelColor,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
-        let content_size = self.inner_view.size(size).await;
-
-        let x_offset = match self.horizontal_alignment {
-            HorizontalAlignment::Left => 0,
-            HorizontalAlignment::Right => size.width as i32 - content_size.width as i32,
-            HorizontalAlignment::Center => (size.width as i32 - content_size.width as i32) / 2,
-        };
+        let content_size = self.inner_view.size(size.into(), environment).await;
 
-        let y_offset = match self.vertical_alignment {
-            VerticalAlignment::Top => 0,
-            VerticalAlignment::Bottom => size.height as i32 - content_size.height as i32,
-            VerticalAlignment::Center => (size.height as i32 - content_size.height as i32) / 2,
-        };
-
-        let mut offset_draw_target = LayoutDrawTarget {
-            original_draw_target: draw_target.original_draw_target,
-            offset: Point {
-                x: draw_target.offset.x + x_offset,
-                y: draw_target.offset.y + y_offset,
-            },
-        };
+        let offset = aligned_offset(
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            size,
+            content_size,
+            environment.layout_direction,
+        );
 
-        self.inner_view
-            .draw(content_size, &mut offset_draw_target)
+        draw_target
+            .draw_child(&self.inner_view, offset, content_size, environment)
             .await;
     }
 
-    async fn size(&self, available_size: Size) -> Size {
-        self.resolve_size(available_size).await
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.resolve_size(proposed_size, environment).await
     }
 }
@@ -0,0 +1,77 @@
+use crate::{
+    draw_target::LayoutDrawTarget,
+    environment::{EnvironmentValues, LayoutDirection},
+    proposed_size::ProposedSize,
+    view::View,
+};
+use core::marker::PhantomData;
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
+
+/// Takes all of the available space (nothing along unspecified or infinite dimensions) and builds its content from the resolved size.
+///
+/// The content is placed at the leading top corner and proposed the full size.
+pub struct GeometryReader<Content, ContentView, Color>
+where
+    Content: Fn(Size) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    content: Content,
+    _marker: PhantomData<Color>,
+}
+
+impl<Content, ContentView, Color> GeometryReader<Content, ContentView, Color>
+where
+    Content: Fn(Size) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(content: Content) -> Self {
+        Self {
+            content,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Color, Content, ContentView> View<Color> for GeometryReader<Content, ContentView, Color>
+where
+    Content: Fn(Size) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let content_view = (self.content)(size);
+        let content_size = content_view.size(size.into(), environment).await;
+
+        let x_offset = match environment.layout_direction {
+            LayoutDirection::LeftToRight => 0,
+            LayoutDirection::RightToLeft => size.width as i32 - content_size.width as i32,
+        };
+
+        draw_target
+            .draw_child(
+                &content_view,
+                Point::new(x_offset, 0),
+                content_size,
+                environment,
+            )
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        _environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        proposed_size.replacing_unbounded(Size::zero())
+    }
+}
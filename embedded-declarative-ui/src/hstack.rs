@@ -1,4 +1,10 @@
-use crate::{alignment::VerticalAlignment, draw_target::LayoutDrawTarget, view::View};
+use crate::{
+    alignment::VerticalAlignment,
+    draw_target::LayoutDrawTarget,
+    environment::{EnvironmentValues, LayoutDirection},
+    proposed_size::{ProposedSize, shrink},
+    view::View,
+};
 use core::{cmp::max, marker::PhantomData};
 use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
 
@@ -33,29 +39,51 @@ where
     Views: HViewTuple<Color>,
     Color: PixelColor,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
         self.views
-            .draw_all(size, self.spacing, self.vertical_alignment, draw_target)
+            .draw_all(
+                size,
+                self.spacing,
+                self.vertical_alignment,
+                environment,
+                draw_target,
+            )
             .await;
     }
 
-    async fn size(&self, available_size: Size) -> Size {
-        self.views.total_size(available_size, self.spacing).await
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.views
+            .total_size(proposed_size, self.spacing, environment)
+            .await
     }
 }
 
 pub trait HViewTuple<Color: PixelColor> {
-    async fn total_size(&self, available_size: Size, spacing: u32) -> Size;
+    async fn total_size(
+        &self,
+        proposed_size: ProposedSize,
+        spacing: u32,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size;
 
     async fn draw_all<Target, Error>(
         &self,
         available_size: Size,
         spacing: u32,
         vertical_alignment: VerticalAlignment,
+        environment: &EnvironmentValues<Color>,
         draw_target: &mut LayoutDrawTarget<'_, Target>,
     ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
@@ -69,7 +97,12 @@ macro_rules! impl_hview_tuple {
             Color: PixelColor,
             $($name: View<Color>),*
         {
-            async fn total_size(&self, available_size: Size, spacing: u32) -> Size {
+            async fn total_size(
+                &self,
+                proposed_size: ProposedSize,
+                spacing: u32,
+                environment: &EnvironmentValues<Color>,
+            ) -> Size {
                 #[allow(non_snake_case)]
                 let ($($name,)*) = self;
                 let mut total_width = 0;
@@ -77,18 +110,18 @@ macro_rules! impl_hview_tuple {
                 let mut count = 0;
 
                 $(
-                    let remaining_size = Size {
-                        width: available_size.width - total_width,
-                        height: available_size.height,
-                    };
-                    let s = $name.size(remaining_size).await;
-                    total_width += s.width;
+                    let remaining_size = ProposedSize::new(
+                        shrink(proposed_size.width, total_width),
+                        proposed_size.height,
+                    );
+                    let s = $name.size(remaining_size, environment).await;
+                    total_width = total_width.saturating_add(s.width);
                     max_height = max(max_height, s.height);
                     count += 1;
                 )*
 
                 if count > 1 {
-                    total_width += spacing * (count - 1);
+                    total_width = total_width.saturating_add(spacing.saturating_mul(count - 1));
                 }
 
                 Size { width: total_width, height: max_height }
@@ -100,6 +133,7 @@ macro_rules! impl_hview_tuple {
                 available_size: Size,
                 spacing: u32,
                 vertical_alignment: VerticalAlignment,
+                environment: &EnvironmentValues<Color>,
                 draw_target: &mut LayoutDrawTarget<'_, Target>,
             ) where
                 Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
@@ -110,11 +144,11 @@ macro_rules! impl_hview_tuple {
                 let mut current_x_offset = 0;
 
                 $(
-                    let remaining_size = Size {
-                        width: available_size.width - current_x_offset,
-                        height: available_size.height,
-                    };
-                    let view_size = $name.size(remaining_size).await;
+                    let remaining_size = ProposedSize::new(
+                        shrink(Some(available_size.width), current_x_offset),
+                        Some(available_size.height),
+                    );
+                    let view_size = $name.size(remaining_size, environment).await;
 
                     let y_offset = match vertical_alignment {
                         VerticalAlignment::Top => 0,
@@ -122,16 +156,24 @@ macro_rules! impl_hview_tuple {
                         VerticalAlignment::Center => (available_size.height - view_size.height) / 2,
                     };
 
-                    let mut child_target = LayoutDrawTarget {
-                        original_draw_target: draw_target.original_draw_target,
-                        offset: Point {
-                            x: draw_target.offset.x + current_x_offset as i32,
-                            y: draw_target.offset.y + y_offset as i32,
-                        },
+                    let x_offset = match environment.layout_direction {
+                        LayoutDirection::LeftToRight => current_x_offset as i32,
+                        LayoutDirection::RightToLeft => {
+                            available_size.width as i32 - current_x_offset as i32 - view_size.width as i32
+                        }
                     };
 
-                    $name.draw(view_size, &mut child_target).await;
-                    current_x_offset += view_size.width + spacing;
+                    draw_target
+                        .draw_child(
+                            $name,
+                            Point::new(x_offset, y_offset as i32),
+                            view_size,
+                            environment,
+                        )
+                        .await;
+                    current_x_offset = current_x_offset
+                        .saturating_add(view_size.width)
+                        .saturating_add(spacing);
                 )*
             }
         }
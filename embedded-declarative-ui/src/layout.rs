@@ -0,0 +1,181 @@
+//! Layout passes over a view tree.
+//!
+//! Every entry point, drawing or not, places the root view with [`draw_root`]: the root is
+//! given the full size it is drawn into, just like calling [`View::draw`] with the display
+//! size directly. Frames seen by layout, hit testing and focus therefore match the pixels drawn.
+
+use crate::{
+    draw_target::LayoutDrawTarget, environment::EnvironmentValues, focus::FocusId,
+    state::Observable, view::View,
+};
+use core::{any::Any, convert::Infallible, marker::PhantomData};
+use embedded_graphics::{
+    Pixel,
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// Resolved frame of a view, as recorded by [`layout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayoutNode {
+    /// Frame of the view in root coordinates.
+    pub frame: Rectangle,
+    /// Index of the parent node, `None` for the root view.
+    pub parent: Option<usize>,
+}
+
+/// Observes the frames of views as they are placed during a draw pass.
+///
+/// Frames are reported in draw order, so later frames are in front of earlier ones.
+pub trait LayoutVisitor {
+    fn enter(&mut self, frame: Rectangle);
+
+    fn exit(&mut self);
+
+    /// Called by [`OnTap`](crate::on_tap::OnTap) before its content with the frame and action of the tappable view.
+    fn tappable(&mut self, _frame: Rectangle, _action: &dyn Any) {}
+
+    /// Called by [`Focusable`](crate::focus::Focusable) before its content with its frame, ID and explicit focus order.
+    fn focusable(&mut self, _frame: Rectangle, _id: FocusId, _order: Option<u16>) {}
+
+    /// Called by views reading shared state with their frame and the state, e.g. by
+    /// [`Watch`](crate::state::Watch) before its content.
+    ///
+    /// Layout changes within views reporting before their content are covered by their frame
+    /// in [`RedrawTracker`](crate::state::RedrawTracker). Views showing whole screens, such as
+    /// [`NavigationStack`](crate::navigation::NavigationStack), report after their content instead.
+    fn observed(&mut self, _frame: Rectangle, _state: &dyn Observable) {}
+}
+
+struct LayoutRecorder<'a> {
+    nodes: &'a mut [LayoutNode],
+    count: usize,
+    current: Option<usize>,
+    unrecorded_depth: usize,
+}
+
+impl LayoutVisitor for LayoutRecorder<'_> {
+    fn enter(&mut self, frame: Rectangle) {
+        if self.count < self.nodes.len() {
+            self.nodes[self.count] = LayoutNode {
+                frame,
+                parent: self.current,
+            };
+            self.current = Some(self.count);
+        } else {
+            self.unrecorded_depth += 1;
+        }
+
+        self.count += 1;
+    }
+
+    fn exit(&mut self) {
+        if self.unrecorded_depth > 0 {
+            self.unrecorded_depth -= 1;
+        } else if let Some(current) = self.current {
+            self.current = self.nodes[current].parent;
+        }
+    }
+}
+
+/// Draw target discarding all pixels, used for passes that only need the layout.
+pub(crate) struct NullDrawTarget<Color> {
+    size: Size,
+    _marker: PhantomData<Color>,
+}
+
+impl<Color> NullDrawTarget<Color> {
+    pub(crate) fn new(size: Size) -> Self {
+        Self {
+            size,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Color> DrawTarget for NullDrawTarget<Color>
+where
+    Color: PixelColor,
+{
+    type Color = Color;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        Ok(())
+    }
+}
+
+impl<Color> OriginDimensions for NullDrawTarget<Color> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+/// Runs `visitor` over the layout of `view` placed into `size`, without drawing any pixels.
+pub async fn visit<Color, V>(
+    view: &V,
+    size: Size,
+    environment: &EnvironmentValues<Color>,
+    visitor: &mut dyn LayoutVisitor,
+) where
+    Color: PixelColor,
+    V: View<Color>,
+{
+    let mut null_target = NullDrawTarget::new(size);
+    let mut draw_target = LayoutDrawTarget {
+        original_draw_target: &mut null_target,
+        offset: Default::default(),
+        visitor: Some(visitor),
+        alpha: None,
+    };
+
+    draw_root(view, size, environment, &mut draw_target).await;
+}
+
+/// Draws `view` as the root of a view tree filling all of `size`, reporting its frame to the
+/// visitor of `draw_target`.
+pub async fn draw_root<Color, V, Target, Error>(
+    view: &V,
+    size: Size,
+    environment: &EnvironmentValues<Color>,
+    draw_target: &mut LayoutDrawTarget<'_, Target>,
+) where
+    Color: PixelColor,
+    V: View<Color>,
+    Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+    Error: 'static,
+{
+    draw_target
+        .draw_child(view, Point::zero(), size, environment)
+        .await;
+}
+
+/// Writes the frames of `view` and its descendants into `nodes` in draw order,
+/// with the root view first.
+///
+/// Returns the number of views laid out. If it exceeds the length of `nodes`,
+/// only the first `nodes.len()` frames were written.
+pub async fn layout<Color, V>(
+    view: &V,
+    size: Size,
+    environment: &EnvironmentValues<Color>,
+    nodes: &mut [LayoutNode],
+) -> usize
+where
+    Color: PixelColor,
+    V: View<Color>,
+{
+    let mut recorder = LayoutRecorder {
+        nodes,
+        count: 0,
+        current: None,
+        unrecorded_depth: 0,
+    };
+
+    visit(view, size, environment, &mut recorder).await;
+
+    recorder.count
+}
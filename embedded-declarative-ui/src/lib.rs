@@ -2,14 +2,37 @@
 #![allow(async_fn_in_trait)]
 
 pub mod alignment;
+pub mod animation;
+pub mod any_view;
+pub mod aspect_ratio;
 pub mod background;
+pub mod blocking;
 pub mod border;
+pub mod button;
 pub mod conditional;
 pub mod draw_target;
+pub mod edge_insets;
+pub mod environment;
+pub mod fill;
+pub mod fixed_size;
+pub mod focus;
 pub mod frame;
+pub mod geometry_reader;
 pub mod hstack;
+pub mod layout;
+pub mod navigation;
+pub mod on_tap;
+pub mod opacity;
+pub mod overlay;
 pub mod padding;
+pub mod presentation;
+pub mod proposed_size;
+pub mod renderer;
+pub mod state;
+pub mod tab_view;
 pub mod text;
+pub mod theme;
+pub mod transition;
 pub mod view;
 pub mod vstack;
 pub mod zstack;
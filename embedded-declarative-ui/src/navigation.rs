@@ -0,0 +1,250 @@
+use crate::{
+    alignment::{HorizontalAlignment, VerticalAlignment},
+    conditional::Either,
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    frame::{Dimension, Frame},
+    hstack::HStack,
+    padding::Padding,
+    proposed_size::ProposedSize,
+    state::Observable,
+    text::Text,
+    view::View,
+    vstack::VStack,
+};
+use core::{cell::Cell, marker::PhantomData};
+use embedded_graphics::{
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// Bounded stack of up to `DEPTH` screens, shared between the application and the
+/// [`NavigationStack`] showing it. The root screen is never popped.
+pub struct NavigationState<Screen, const DEPTH: usize>
+where
+    Screen: Copy,
+{
+    screens: Cell<[Option<Screen>; DEPTH]>,
+    depth: Cell<usize>,
+    dirty: Cell<bool>,
+}
+
+#[allow(dead_code)]
+impl<Screen, const DEPTH: usize> NavigationState<Screen, DEPTH>
+where
+    Screen: Copy,
+{
+    pub fn new(root: Screen) -> Self {
+        const { assert!(DEPTH > 0, "the stack needs room for the root screen") };
+
+        let mut screens = [None; DEPTH];
+        screens[0] = Some(root);
+
+        Self {
+            screens: Cell::new(screens),
+            depth: Cell::new(1),
+            dirty: Cell::new(true),
+        }
+    }
+
+    /// Screen currently shown.
+    pub fn top(&self) -> Screen {
+        self.screens.get()[self.depth.get() - 1].unwrap()
+    }
+
+    /// Number of screens on the stack, including the root.
+    pub fn depth(&self) -> usize {
+        self.depth.get()
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.depth.get() > 1
+    }
+
+    /// Shows `screen` on top of the current one, handing it back if the stack is full.
+    pub fn push(&self, screen: Screen) -> Result<(), Screen> {
+        let depth = self.depth.get();
+        if depth == DEPTH {
+            return Err(screen);
+        }
+
+        let mut screens = self.screens.get();
+        screens[depth] = Some(screen);
+        self.screens.set(screens);
+        self.depth.set(depth + 1);
+        self.dirty.set(true);
+        Ok(())
+    }
+
+    /// Returns to the previous screen and returns the one removed, or `None` at the root.
+    pub fn pop(&self) -> Option<Screen> {
+        let depth = self.depth.get();
+        if depth == 1 {
+            return None;
+        }
+
+        let mut screens = self.screens.get();
+        let popped = screens[depth - 1].take();
+        self.screens.set(screens);
+        self.depth.set(depth - 1);
+        self.dirty.set(true);
+        popped
+    }
+
+    /// Pops back to the root screen.
+    pub fn pop_to_root(&self) {
+        while self.pop().is_some() {}
+    }
+
+    /// Swaps the current screen for `screen` and returns the one replaced.
+    pub fn replace(&self, screen: Screen) -> Screen {
+        let mut screens = self.screens.get();
+        let replaced = screens[self.depth.get() - 1].replace(screen).unwrap();
+        self.screens.set(screens);
+        self.dirty.set(true);
+        replaced
+    }
+}
+
+impl<Screen, const DEPTH: usize> Observable for NavigationState<Screen, DEPTH>
+where
+    Screen: Copy,
+{
+    fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    fn mark_clean(&self) {
+        self.dirty.set(false);
+    }
+}
+
+/// Shows the top screen of a [`NavigationState`], built by `content`.
+///
+/// Screens of different types can be returned through `view_match!` or as an [`crate::any_view::AnyView`].
+pub struct NavigationStack<'a, Screen, Content, ContentView, Color, const DEPTH: usize>
+where
+    Screen: Copy,
+    Content: Fn(Screen) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    state: &'a NavigationState<Screen, DEPTH>,
+    title: Option<fn(Screen) -> &'static str>,
+    content: Content,
+    _marker: PhantomData<Color>,
+}
+
+impl<'a, Screen, Content, ContentView, Color, const DEPTH: usize>
+    NavigationStack<'a, Screen, Content, ContentView, Color, DEPTH>
+where
+    Screen: Copy,
+    Content: Fn(Screen) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(state: &'a NavigationState<Screen, DEPTH>, content: Content) -> Self {
+        Self {
+            state,
+            title: None,
+            content,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Draws a title bar above the screen with its `title` and a back indicator when
+    /// there is a screen to go back to.
+    ///
+    /// The title is plain [`Text`], so the environment must provide a font and foreground
+    /// color, e.g. through a theme. Without a font the title bar collapses to its padding.
+    pub fn with_title_bar(
+        state: &'a NavigationState<Screen, DEPTH>,
+        title: fn(Screen) -> &'static str,
+        content: Content,
+    ) -> Self {
+        Self {
+            title: Some(title),
+            ..Self::new(state, content)
+        }
+    }
+
+    fn view(&self, screen: Screen, content: &ContentView) -> impl View<Color> {
+        let Some(title) = self.title else {
+            return Either::Right(content);
+        };
+
+        let title = Text::plain(title(screen));
+        let title_row = match self.state.can_go_back() {
+            true => Either::Left(HStack::new(
+                2,
+                VerticalAlignment::Center,
+                (Text::plain("<"), title),
+            )),
+            false => Either::Right(title),
+        };
+
+        let title_bar = Frame::new(
+            Dimension::Max,
+            Dimension::Ideal,
+            HorizontalAlignment::Leading,
+            VerticalAlignment::Center,
+            Padding::all(2, title_row),
+        );
+
+        Either::Left(VStack::new(
+            0,
+            HorizontalAlignment::Leading,
+            (title_bar, content),
+        ))
+    }
+}
+
+impl<Screen, Content, ContentView, Color, const DEPTH: usize> View<Color>
+    for NavigationStack<'_, Screen, Content, ContentView, Color, DEPTH>
+where
+    Screen: Copy,
+    Content: Fn(Screen) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let screen = self.state.top();
+        let content = (self.content)(screen);
+
+        draw_target
+            .draw_child(
+                &self.view(screen, &content),
+                Point::zero(),
+                size,
+                environment,
+            )
+            .await;
+
+        // Reported after the screen, so layout changes within it are still noticed, see
+        // `LayoutVisitor::observed`.
+        if let Some(visitor) = &mut draw_target.visitor {
+            visitor.observed(Rectangle::new(draw_target.offset, size), self.state);
+        }
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        let screen = self.state.top();
+        let content = (self.content)(screen);
+
+        self.view(screen, &content)
+            .size(proposed_size, environment)
+            .await
+    }
+}
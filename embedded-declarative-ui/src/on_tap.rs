@@ -0,0 +1,251 @@
+use crate::{
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    layout::{self, LayoutVisitor},
+    proposed_size::ProposedSize,
+    view::View,
+};
+use core::{any::Any, marker::PhantomData};
+use embedded_graphics::{
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// Makes `inner_view` tappable, reporting `action` from [`hit_test`] when a tap lands on it.
+pub struct OnTap<InnerView, Action, Color>
+where
+    InnerView: View<Color>,
+    Action: Clone + 'static,
+    Color: PixelColor,
+{
+    action: Action,
+    inner_view: InnerView,
+    _marker: PhantomData<Color>,
+}
+
+impl<InnerView, Action, Color> OnTap<InnerView, Action, Color>
+where
+    InnerView: View<Color>,
+    Action: Clone + 'static,
+    Color: PixelColor,
+{
+    pub fn new(action: Action, inner_view: InnerView) -> Self {
+        Self {
+            action,
+            inner_view,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Color, InnerView, Action> View<Color> for OnTap<InnerView, Action, Color>
+where
+    InnerView: View<Color>,
+    Action: Clone + 'static,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        if let Some(visitor) = &mut draw_target.visitor {
+            visitor.tappable(Rectangle::new(draw_target.offset, size), &self.action);
+        }
+
+        draw_target
+            .draw_child(&self.inner_view, Point::zero(), size, environment)
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.inner_view.size(proposed_size, environment).await
+    }
+}
+
+struct HitTester<Action> {
+    point: Point,
+    depth: usize,
+    clipped_depth: Option<usize>,
+    /// Depth of the `OnTap` the action was found in, until its frame is left.
+    tap_depth: Option<usize>,
+    /// Depth and action of the outermost `OnTap` whose frame hasn't been left yet.
+    outer_tap: Option<(usize, Action)>,
+    /// Frame entered last, while none of its children have been entered.
+    leaf_frame: Option<Rectangle>,
+    action: Option<Action>,
+}
+
+impl<Action> LayoutVisitor for HitTester<Action>
+where
+    Action: Clone + 'static,
+{
+    fn enter(&mut self, frame: Rectangle) {
+        self.depth += 1;
+        self.leaf_frame = Some(frame);
+
+        if self.clipped_depth.is_none() && !frame.contains(self.point) {
+            self.clipped_depth = Some(self.depth);
+        }
+    }
+
+    fn exit(&mut self) {
+        // Views without children draw themselves, so they cover taps on views behind them.
+        if let Some(frame) = self.leaf_frame.take()
+            && self.tap_depth.is_none()
+            && self.clipped_depth.is_none()
+            && frame.contains(self.point)
+        {
+            self.action = self.outer_tap.as_ref().map(|(_, action)| action.clone());
+        }
+
+        if self.tap_depth == Some(self.depth) {
+            self.tap_depth = None;
+        }
+
+        if self
+            .outer_tap
+            .as_ref()
+            .is_some_and(|(depth, _)| *depth == self.depth)
+        {
+            self.outer_tap = None;
+        }
+
+        if self.clipped_depth == Some(self.depth) {
+            self.clipped_depth = None;
+        }
+
+        self.depth -= 1;
+    }
+
+    fn tappable(&mut self, frame: Rectangle, action: &dyn Any) {
+        if self.clipped_depth.is_some() || !frame.contains(self.point) {
+            return;
+        }
+
+        if let Some(action) = action.downcast_ref::<Action>() {
+            self.action = Some(action.clone());
+            self.tap_depth = Some(self.depth);
+            self.outer_tap
+                .get_or_insert_with(|| (self.depth, action.clone()));
+        }
+    }
+}
+
+/// Returns the action of the topmost [`OnTap`] of `view` placed into `size` within `environment` containing `point`.
+///
+/// Views drawn later are in front of earlier ones, and taps outside the frame of any
+/// ancestor are ignored. Views without children, such as a [`Fill`](crate::fill::Fill) or
+/// [`Text`](crate::text::Text), block taps on the views behind them, while containers such as
+/// stacks and frames let them through. Only `OnTap` modifiers with an action of type `Action`
+/// are considered.
+pub async fn hit_test<Action, Color, V>(
+    view: &V,
+    size: Size,
+    environment: &EnvironmentValues<Color>,
+    point: Point,
+) -> Option<Action>
+where
+    Action: Clone + 'static,
+    Color: PixelColor,
+    V: View<Color>,
+{
+    let mut hit_tester = HitTester {
+        point,
+        depth: 0,
+        clipped_depth: None,
+        tap_depth: None,
+        outer_tap: None,
+        leaf_frame: None,
+        action: None,
+    };
+
+    layout::visit(view, size, environment, &mut hit_tester).await;
+
+    hit_tester.action
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        alignment::{HorizontalAlignment, VerticalAlignment},
+        blocking::block_on,
+        fill::Fill,
+        frame::{Dimension, Frame},
+        zstack::{ZStack, ZViewTuple},
+    };
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    const SIZE: Size = Size::new(20, 20);
+
+    fn tap(view: &impl View<BinaryColor>, point: Point) -> Option<u8> {
+        block_on(hit_test(view, SIZE, &EnvironmentValues::default(), point))
+    }
+
+    fn zstack<Views>(views: Views) -> ZStack<Views, BinaryColor>
+    where
+        Views: ZViewTuple<BinaryColor>,
+    {
+        ZStack::new(HorizontalAlignment::Leading, VerticalAlignment::Top, views)
+    }
+
+    /// 4x4 view in the top leading corner of a frame filling all space.
+    fn corner() -> impl View<BinaryColor> {
+        Frame::new(
+            Dimension::Max,
+            Dimension::Max,
+            HorizontalAlignment::Leading,
+            VerticalAlignment::Top,
+            Frame::new(
+                Dimension::Constant(4),
+                Dimension::Constant(4),
+                HorizontalAlignment::Leading,
+                VerticalAlignment::Top,
+                Fill::new(BinaryColor::Off),
+            ),
+        )
+    }
+
+    #[test]
+    fn views_in_front_block_taps() {
+        let covered = zstack((
+            OnTap::new(1u8, Fill::new(BinaryColor::On)),
+            Fill::new(BinaryColor::Off),
+        ));
+        let uncovered = zstack((
+            Fill::new(BinaryColor::Off),
+            OnTap::new(1u8, Fill::new(BinaryColor::On)),
+        ));
+
+        assert_eq!(tap(&covered, Point::new(10, 10)), None);
+        assert_eq!(tap(&uncovered, Point::new(10, 10)), Some(1));
+    }
+
+    #[test]
+    fn containers_in_front_let_taps_through() {
+        let view = zstack((OnTap::new(1u8, Fill::new(BinaryColor::On)), corner()));
+
+        assert_eq!(tap(&view, Point::new(1, 1)), None);
+        assert_eq!(tap(&view, Point::new(10, 10)), Some(1));
+    }
+
+    #[test]
+    fn views_covering_a_nested_tap_fall_back_to_the_outer_one() {
+        let view = OnTap::new(
+            1u8,
+            zstack((OnTap::new(2u8, Fill::new(BinaryColor::On)), corner())),
+        );
+
+        assert_eq!(tap(&view, Point::new(1, 1)), Some(1));
+        assert_eq!(tap(&view, Point::new(10, 10)), Some(2));
+    }
+}
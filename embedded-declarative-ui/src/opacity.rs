@@ -0,0 +1,211 @@
+//! Semi-transparent drawing.
+//!
+//! Pixels are blended with the ones below them when drawing through a [`BlendingDrawTarget`],
+//! which needs a framebuffer that can be read back, see [`draw_blended`]. Everywhere else
+//! [`Opacity`] falls back to ordered dithering, which also works on monochrome displays.
+
+use crate::{
+    animation::Interpolate,
+    draw_target::{LayoutDrawTarget, Mask},
+    environment::EnvironmentValues,
+    layout,
+    proposed_size::ProposedSize,
+    view::View,
+};
+use core::{cell::Cell, iter::once, marker::PhantomData};
+use embedded_graphics::{
+    Pixel,
+    image::GetPixel,
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+};
+
+/// Draws its content with `opacity` between 0 (invisible) and 1 (opaque).
+pub struct Opacity<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    opacity: f32,
+    inner_view: InnerView,
+    _marker: PhantomData<Color>,
+}
+
+impl<InnerView, Color> Opacity<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(opacity: f32, inner_view: InnerView) -> Self {
+        Self {
+            opacity: opacity.clamp(0.0, 1.0),
+            inner_view,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Color, InnerView> View<Color> for Opacity<InnerView, Color>
+where
+    InnerView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let Some(alpha) = draw_target.alpha else {
+            let alpha = Cell::new((u8::MAX as f32 * self.opacity + 0.5) as u8);
+            let mut dithering_target = DitheringDrawTarget {
+                original_draw_target: &mut *draw_target.original_draw_target,
+                alpha: &alpha,
+            };
+
+            LayoutDrawTarget {
+                original_draw_target: &mut dithering_target,
+                offset: draw_target.offset,
+                visitor: match &mut draw_target.visitor {
+                    Some(visitor) => Some(&mut **visitor),
+                    None => None,
+                },
+                alpha: Some(&alpha),
+            }
+            .draw_child(&self.inner_view, Point::zero(), size, environment)
+            .await;
+            return;
+        };
+
+        let parent_alpha = alpha.get();
+        alpha.set((parent_alpha as f32 * self.opacity + 0.5) as u8);
+
+        draw_target
+            .draw_child(&self.inner_view, Point::zero(), size, environment)
+            .await;
+
+        alpha.set(parent_alpha);
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.inner_view.size(proposed_size, environment).await
+    }
+}
+
+/// Keeps the share of pixels given by the current `alpha` in an ordered dither pattern.
+struct DitheringDrawTarget<'a, T> {
+    original_draw_target: &'a mut T,
+    alpha: &'a Cell<u8>,
+}
+
+impl<T, Color> DrawTarget for DitheringDrawTarget<'_, T>
+where
+    T: DrawTarget<Color = Color> + OriginDimensions,
+    Color: PixelColor,
+{
+    type Color = Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mask = Mask {
+            clip: None,
+            coverage: Some(((self.alpha.get() as u32 * 16 + 127) / 255) as u8),
+            inverted: false,
+        };
+
+        self.original_draw_target.draw_iter(
+            pixels
+                .into_iter()
+                .filter(|Pixel(point, _)| mask.contains(*point)),
+        )
+    }
+}
+
+impl<T> OriginDimensions for DitheringDrawTarget<'_, T>
+where
+    T: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.original_draw_target.size()
+    }
+}
+
+/// Blends the pixels drawn with the current `alpha` with the pixels already in the framebuffer.
+pub struct BlendingDrawTarget<'a, T> {
+    pub framebuffer: &'a mut T,
+    pub alpha: &'a Cell<u8>,
+}
+
+impl<T, Color> DrawTarget for BlendingDrawTarget<'_, T>
+where
+    T: DrawTarget<Color = Color> + GetPixel<Color = Color> + OriginDimensions,
+    Color: PixelColor + Interpolate,
+{
+    type Color = Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let alpha = self.alpha.get();
+        if alpha == u8::MAX {
+            return self.framebuffer.draw_iter(pixels);
+        }
+
+        let progress = alpha as f32 / u8::MAX as f32;
+        for Pixel(point, color) in pixels {
+            let Some(background) = self.framebuffer.pixel(point) else {
+                continue;
+            };
+
+            let blended = Color::interpolate(background, color, progress);
+            self.framebuffer.draw_iter(once(Pixel(point, blended)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> OriginDimensions for BlendingDrawTarget<'_, T>
+where
+    T: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.framebuffer.size()
+    }
+}
+
+/// Draws `view` as the root filling `size` into `framebuffer`, see [`layout::draw_root`],
+/// blending the content of [`Opacity`] modifiers with the pixels below it.
+pub async fn draw_blended<Color, V, T>(
+    view: &V,
+    size: Size,
+    environment: &EnvironmentValues<Color>,
+    framebuffer: &mut T,
+) where
+    Color: PixelColor + Interpolate,
+    V: View<Color>,
+    T: DrawTarget<Color = Color> + GetPixel<Color = Color> + OriginDimensions,
+    T::Error: 'static,
+{
+    let alpha = Cell::new(u8::MAX);
+    let mut blending_target = BlendingDrawTarget {
+        framebuffer,
+        alpha: &alpha,
+    };
+
+    let mut draw_target = LayoutDrawTarget::new(&mut blending_target);
+    draw_target.alpha = Some(&alpha);
+
+    layout::draw_root(view, size, environment, &mut draw_target).await;
+}
@@ -0,0 +1,90 @@
+use crate::{
+    alignment::{HorizontalAlignment, VerticalAlignment, aligned_offset},
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    proposed_size::ProposedSize,
+    view::View,
+};
+use core::marker::PhantomData;
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
+
+/// Draws `overlay_view` in front of `inner_view`.
+///
+/// Unlike a `ZStack`, the overlay is proposed the layout size of the inner view
+/// and the modified view keeps that size.
+pub struct Overlay<InnerView, OverlayView, Color>
+where
+    InnerView: View<Color>,
+    OverlayView: View<Color>,
+    Color: PixelColor,
+{
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    overlay_view: OverlayView,
+    inner_view: InnerView,
+    _marker: PhantomData<Color>,
+}
+
+impl<InnerView, OverlayView, Color> Overlay<InnerView, OverlayView, Color>
+where
+    InnerView: View<Color>,
+    OverlayView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+        overlay_view: OverlayView,
+        inner_view: InnerView,
+    ) -> Self {
+        Self {
+            horizontal_alignment,
+            vertical_alignment,
+            overlay_view,
+            inner_view,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Color, InnerView, OverlayView> View<Color> for Overlay<InnerView, OverlayView, Color>
+where
+    InnerView: View<Color>,
+    OverlayView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        draw_target
+            .draw_child(&self.inner_view, Point::zero(), size, environment)
+            .await;
+
+        let overlay_size = self.overlay_view.size(size.into(), environment).await;
+        let offset = aligned_offset(
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            size,
+            overlay_size,
+            environment.layout_direction,
+        );
+
+        draw_target
+            .draw_child(&self.overlay_view, offset, overlay_size, environment)
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.inner_view.size(proposed_size, environment).await
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{draw_target::LayoutDrawTarget, view::View};
+use crate::{
+    draw_target::LayoutDrawTarget,
+    edge_insets::EdgeInsets,
+    environment::EnvironmentValues,
+    proposed_size::{ProposedSize, shrink},
+    view::View,
+};
 use core::marker::PhantomData;
 use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
 
@@ -7,10 +13,7 @@ where
     InnerView: View<Color>,
     Color: PixelColor,
 {
-    left: u32,
-    top: u32,
-    right: u32,
-    bottom: u32,
+    insets: EdgeInsets,
     inner_view: InnerView,
     _marker: PhantomData<Color>,
 }
@@ -21,43 +24,50 @@ where
     InnerView: View<Color>,
     Color: PixelColor,
 {
-    pub fn new(left: u32, top: u32, right: u32, bottom: u32, inner_view: InnerView) -> Self {
+    pub fn new(insets: EdgeInsets, inner_view: InnerView) -> Self {
         Self {
-            left,
-            top,
-            right,
-            bottom,
+            insets,
             inner_view,
             _marker: PhantomData,
         }
     }
 
     pub fn top(top: u32, inner_view: InnerView) -> Self {
-        Self::new(0, top, 0, 0, inner_view)
+        Self::new(EdgeInsets::top(top), inner_view)
     }
 
     pub fn bottom(bottom: u32, inner_view: InnerView) -> Self {
-        Self::new(0, 0, 0, bottom, inner_view)
+        Self::new(EdgeInsets::bottom(bottom), inner_view)
     }
 
+    pub fn leading(leading: u32, inner_view: InnerView) -> Self {
+        Self::new(EdgeInsets::leading(leading), inner_view)
+    }
+
+    pub fn trailing(trailing: u32, inner_view: InnerView) -> Self {
+        Self::new(EdgeInsets::trailing(trailing), inner_view)
+    }
+
+    #[deprecated(note = "use `Padding::leading`, which mirrors the same way")]
     pub fn left(left: u32, inner_view: InnerView) -> Self {
-        Self::new(left, 0, 0, 0, inner_view)
+        Self::leading(left, inner_view)
     }
 
+    #[deprecated(note = "use `Padding::trailing`, which mirrors the same way")]
     pub fn right(right: u32, inner_view: InnerView) -> Self {
-        Self::new(0, 0, right, 0, inner_view)
+        Self::trailing(right, inner_view)
     }
 
     pub fn horizontal(horizontal: u32, inner_view: InnerView) -> Self {
-        Self::new(horizontal, 0, horizontal, 0, inner_view)
+        Self::new(EdgeInsets::horizontal(horizontal), inner_view)
     }
 
     pub fn vertical(vertical: u32, inner_view: InnerView) -> Self {
-        Self::new(0, vertical, 0, vertical, inner_view)
+        Self::new(EdgeInsets::vertical(vertical), inner_view)
     }
 
     pub fn all(all: u32, inner_view: InnerView) -> Self {
-        Self::new(all, all, all, all, inner_view)
+        Self::new(EdgeInsets::all(all), inner_view)
     }
 }
 
@@ -66,40 +76,56 @@ where
     InnerView: View<Color>,
     Color: PixelColor,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
+        let insets = &self.insets;
         let content_size = Size::new(
-            size.width - (self.left + self.right),
-            size.height - (self.top + self.bottom),
+            size.width - insets.horizontal_total(),
+            size.height - insets.vertical_total(),
         );
 
-        let mut offset_draw_target = LayoutDrawTarget {
-            original_draw_target: draw_target.original_draw_target,
-            offset: Point {
-                x: draw_target.offset.x + self.left as i32,
-                y: draw_target.offset.y + self.top as i32,
-            },
-        };
-
-        self.inner_view
-            .draw(content_size, &mut offset_draw_target)
+        draw_target
+            .draw_child(
+                &self.inner_view,
+                Point::new(
+                    insets.left(environment.layout_direction) as i32,
+                    insets.top as i32,
+                ),
+                content_size,
+                environment,
+            )
             .await;
     }
 
-    async fn size(&self, available_size: Size) -> Size {
-        let content_available_size = Size::new(
-            available_size.width - (self.left + self.right),
-            available_size.height - (self.top + self.bottom),
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        let content_proposed_size = ProposedSize::new(
+            shrink(proposed_size.width, self.insets.horizontal_total()),
+            shrink(proposed_size.height, self.insets.vertical_total()),
         );
 
-        let content_size = self.inner_view.size(content_available_size).await;
+        let content_size = self
+            .inner_view
+            .size(content_proposed_size, environment)
+            .await;
 
         Size::new(
-            content_size.width + self.left + self.right,
-            content_size.height + self.top + self.bottom,
+            content_size
+                .width
+                .saturating_add(self.insets.horizontal_total()),
+            content_size
+                .height
+                .saturating_add(self.insets.vertical_total()),
         )
     }
 }
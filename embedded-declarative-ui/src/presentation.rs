@@ -0,0 +1,319 @@
+use crate::{
+    background::Background,
+    border::Border,
+    draw_target::{Inert, LayoutDrawTarget},
+    environment::EnvironmentValues,
+    padding::Padding,
+    proposed_size::{ProposedSize, shrink},
+    state::Observable,
+    view::View,
+};
+use core::{cell::Cell, marker::PhantomData};
+use embedded_graphics::{
+    Pixel,
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::{PointsIter, Rectangle},
+};
+
+/// Space kept free around modals and toasts.
+const MARGIN: u32 = 4;
+const CONTENT_PADDING: u32 = 3;
+
+/// Modal and toast currently presented, shared between the application and the [`Presentation`] showing them.
+pub struct PresentationState<Modal, Toast>
+where
+    Modal: Copy,
+    Toast: Copy,
+{
+    modal: Cell<Option<Modal>>,
+    toast: Cell<Option<(Toast, u64)>>,
+    dirty: Cell<bool>,
+}
+
+impl<Modal, Toast> Default for PresentationState<Modal, Toast>
+where
+    Modal: Copy,
+    Toast: Copy,
+{
+    fn default() -> Self {
+        Self {
+            modal: Cell::new(None),
+            toast: Cell::new(None),
+            dirty: Cell::new(true),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<Modal, Toast> PresentationState<Modal, Toast>
+where
+    Modal: Copy,
+    Toast: Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn modal(&self) -> Option<Modal> {
+        self.modal.get()
+    }
+
+    /// Presents `modal` over the current screen, replacing any modal already shown.
+    pub fn present(&self, modal: Modal) {
+        self.modal.set(Some(modal));
+        self.dirty.set(true);
+    }
+
+    /// Dismisses the modal and returns it.
+    pub fn dismiss(&self) -> Option<Modal> {
+        self.dirty.set(true);
+        self.modal.take()
+    }
+
+    pub fn toast(&self) -> Option<Toast> {
+        self.toast.get().map(|(toast, _)| toast)
+    }
+
+    /// Shows `toast` until `duration_ms` after `now_ms`, replacing any toast already shown.
+    ///
+    /// Timestamps are in milliseconds of any monotonic clock, see [`Self::expire_toast`].
+    pub fn show_toast(&self, toast: Toast, now_ms: u64, duration_ms: u64) {
+        self.toast
+            .set(Some((toast, now_ms.saturating_add(duration_ms))));
+        self.dirty.set(true);
+    }
+
+    pub fn hide_toast(&self) {
+        self.toast.set(None);
+        self.dirty.set(true);
+    }
+
+    /// Hides the toast once its time is up at `now_ms`, returning whether it was hidden
+    /// and the screen needs to be redrawn.
+    pub fn expire_toast(&self, now_ms: u64) -> bool {
+        match self.toast.get() {
+            Some((_, expires_at)) if now_ms >= expires_at => {
+                self.hide_toast();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<Modal, Toast> Observable for PresentationState<Modal, Toast>
+where
+    Modal: Copy,
+    Toast: Copy,
+{
+    fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    fn mark_clean(&self) {
+        self.dirty.set(false);
+    }
+}
+
+/// Backdrop separating a modal from the screen below it.
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+pub enum ModalStyle {
+    /// The modal is bordered and the screen is left as is.
+    Bordered,
+    /// The modal is bordered and the screen is dimmed by a checkerboard of the foreground color.
+    Dimmed,
+}
+
+/// Presents the modal and toast of a [`PresentationState`] over `base_view`, in the same draw pass.
+///
+/// The modal is centered and the toast placed at the bottom, both on the theme background.
+/// While a modal is presented, the views below it don't take part in hit testing and focus,
+/// but are still redrawn when the states they watch change.
+pub struct Presentation<
+    'a,
+    BaseView,
+    Modal,
+    ModalContent,
+    ModalView,
+    Toast,
+    ToastContent,
+    ToastView,
+    Color,
+> where
+    BaseView: View<Color>,
+    Modal: Copy,
+    ModalContent: Fn(Modal) -> ModalView,
+    ModalView: View<Color>,
+    Toast: Copy,
+    ToastContent: Fn(Toast) -> ToastView,
+    ToastView: View<Color>,
+    Color: PixelColor,
+{
+    state: &'a PresentationState<Modal, Toast>,
+    modal_style: ModalStyle,
+    modal_content: ModalContent,
+    toast_content: ToastContent,
+    base_view: BaseView,
+    _marker: PhantomData<Color>,
+}
+
+impl<'a, BaseView, Modal, ModalContent, ModalView, Toast, ToastContent, ToastView, Color>
+    Presentation<
+        'a,
+        BaseView,
+        Modal,
+        ModalContent,
+        ModalView,
+        Toast,
+        ToastContent,
+        ToastView,
+        Color,
+    >
+where
+    BaseView: View<Color>,
+    Modal: Copy,
+    ModalContent: Fn(Modal) -> ModalView,
+    ModalView: View<Color>,
+    Toast: Copy,
+    ToastContent: Fn(Toast) -> ToastView,
+    ToastView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(
+        state: &'a PresentationState<Modal, Toast>,
+        modal_style: ModalStyle,
+        modal_content: ModalContent,
+        toast_content: ToastContent,
+        base_view: BaseView,
+    ) -> Self {
+        Self {
+            state,
+            modal_style,
+            modal_content,
+            toast_content,
+            base_view,
+            _marker: PhantomData,
+        }
+    }
+
+    fn dim<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let Some(color) = environment.foreground_color else {
+            return;
+        };
+
+        let pixels = Rectangle::new(draw_target.offset, size)
+            .points()
+            .filter(|point| (point.x + point.y) % 2 == 0)
+            .map(|point| Pixel(point, color));
+        _ = draw_target.original_draw_target.draw_iter(pixels);
+    }
+}
+
+fn chrome<Color, V>(content: V) -> impl View<Color>
+where
+    Color: PixelColor,
+    V: View<Color>,
+{
+    Background::themed(Border::themed(1, 0, Padding::all(CONTENT_PADDING, content)))
+}
+
+impl<BaseView, Modal, ModalContent, ModalView, Toast, ToastContent, ToastView, Color> View<Color>
+    for Presentation<
+        '_,
+        BaseView,
+        Modal,
+        ModalContent,
+        ModalView,
+        Toast,
+        ToastContent,
+        ToastView,
+        Color,
+    >
+where
+    BaseView: View<Color>,
+    Modal: Copy,
+    ModalContent: Fn(Modal) -> ModalView,
+    ModalView: View<Color>,
+    Toast: Copy,
+    ToastContent: Fn(Toast) -> ToastView,
+    ToastView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let modal = self.state.modal();
+
+        if modal.is_some() {
+            draw_target
+                .draw_child(&Inert(&self.base_view), Point::zero(), size, environment)
+                .await;
+        } else {
+            draw_target
+                .draw_child(&self.base_view, Point::zero(), size, environment)
+                .await;
+        }
+
+        let available_size = ProposedSize::new(
+            shrink(Some(size.width), 2 * MARGIN),
+            shrink(Some(size.height), 2 * MARGIN),
+        );
+
+        if let Some(modal) = modal {
+            if let ModalStyle::Dimmed = self.modal_style {
+                self.dim(size, environment, draw_target);
+            }
+
+            let modal_view = chrome((self.modal_content)(modal));
+            let modal_size = modal_view.size(available_size, environment).await;
+            let offset = Point::new(
+                (size.width as i32 - modal_size.width as i32) / 2,
+                (size.height as i32 - modal_size.height as i32) / 2,
+            );
+            draw_target
+                .draw_child(&modal_view, offset, modal_size, environment)
+                .await;
+        }
+
+        if let Some(toast) = self.state.toast() {
+            let toast_view = chrome((self.toast_content)(toast));
+            let toast_size = toast_view.size(available_size, environment).await;
+            let offset = Point::new(
+                (size.width as i32 - toast_size.width as i32) / 2,
+                size.height as i32 - MARGIN as i32 - toast_size.height as i32,
+            );
+            draw_target
+                .draw_child(&toast_view, offset, toast_size, environment)
+                .await;
+        }
+
+        // Reported after the content, so layout changes within it are still noticed, see
+        // `LayoutVisitor::observed`.
+        if let Some(visitor) = &mut draw_target.visitor {
+            visitor.observed(Rectangle::new(draw_target.offset, size), self.state);
+        }
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.base_view.size(proposed_size, environment).await
+    }
+}
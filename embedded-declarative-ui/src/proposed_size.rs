@@ -0,0 +1,138 @@
+use embedded_graphics::prelude::Size;
+
+/// Dimension of an infinite proposal, asking a view for the largest size it can take.
+pub const INFINITY: u32 = u32::MAX;
+
+/// Size offered to a view during layout.
+///
+/// A `None` dimension is unspecified and asks the view for its ideal size along that axis,
+/// `Some(0)` for its minimum size and `Some(INFINITY)` for its maximum size.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ProposedSize {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+impl ProposedSize {
+    pub const UNSPECIFIED: ProposedSize = ProposedSize::new(None, None);
+    pub const ZERO: ProposedSize = ProposedSize::new(Some(0), Some(0));
+    pub const INFINITE: ProposedSize = ProposedSize::new(Some(INFINITY), Some(INFINITY));
+
+    pub const fn new(width: Option<u32>, height: Option<u32>) -> Self {
+        Self { width, height }
+    }
+
+    /// Resolves unspecified dimensions with the corresponding dimension of `size`.
+    pub fn replacing_unspecified(self, size: Size) -> Size {
+        Size::new(
+            self.width.unwrap_or(size.width),
+            self.height.unwrap_or(size.height),
+        )
+    }
+
+    /// Resolves unspecified and infinite dimensions with the corresponding dimension of `size`,
+    /// for views taking all of the space they are offered.
+    pub fn replacing_unbounded(self, size: Size) -> Size {
+        let resolve = |dimension: Option<u32>, fallback| match dimension {
+            Some(INFINITY) | None => fallback,
+            Some(dimension) => dimension,
+        };
+
+        Size::new(
+            resolve(self.width, size.width),
+            resolve(self.height, size.height),
+        )
+    }
+}
+
+impl From<Size> for ProposedSize {
+    fn from(size: Size) -> Self {
+        Self::new(Some(size.width), Some(size.height))
+    }
+}
+
+/// Removes `amount` from a proposed dimension, keeping unspecified and infinite proposals as they are.
+pub(crate) fn shrink(dimension: Option<u32>, amount: u32) -> Option<u32> {
+    dimension.map(|dimension| {
+        if dimension == INFINITY {
+            INFINITY
+        } else {
+            dimension.saturating_sub(amount)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        alignment::{HorizontalAlignment, VerticalAlignment},
+        blocking::BlockingView,
+        button::Button,
+        environment::EnvironmentValues,
+        fill::Fill,
+        focus::{FocusId, FocusState},
+        frame::{Dimension, Frame},
+        hstack::HStack,
+        padding::Padding,
+        tab_view::{TabBar, TabView},
+        theme::Theme,
+        view::View,
+        vstack::VStack,
+        zstack::ZStack,
+    };
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use u8g2_fonts::{FontRenderer, fonts::u8g2_font_5x7_tf};
+
+    fn max() -> impl View<BinaryColor> {
+        Frame::new(
+            Dimension::Max,
+            Dimension::Max,
+            HorizontalAlignment::Leading,
+            VerticalAlignment::Top,
+            Fill::new(BinaryColor::On),
+        )
+    }
+
+    fn measure(view: &impl View<BinaryColor>) -> Size {
+        let font = FontRenderer::new::<u8g2_font_5x7_tf>();
+        let environment = EnvironmentValues::with_theme(Theme::light(font.clone(), font));
+        view.size_blocking(ProposedSize::INFINITE, &environment)
+    }
+
+    #[test]
+    fn containers_of_maximal_views_saturate_infinite_proposals() {
+        let infinite = Size::new(INFINITY, INFINITY);
+        let focus_state = FocusState::new();
+
+        assert_eq!(measure(&Padding::all(2, max())), infinite);
+        assert_eq!(
+            measure(&HStack::new(2, VerticalAlignment::Top, (max(), max()))),
+            infinite
+        );
+        assert_eq!(
+            measure(&VStack::new(
+                2,
+                HorizontalAlignment::Leading,
+                (max(), max())
+            )),
+            infinite
+        );
+        assert_eq!(
+            measure(&ZStack::new(
+                HorizontalAlignment::Leading,
+                VerticalAlignment::Top,
+                (max(), max())
+            )),
+            infinite
+        );
+        assert_eq!(
+            measure(&Button::themed(&focus_state, FocusId(1), (), |_| max())),
+            infinite
+        );
+        assert_eq!(
+            measure(&TabView::new(0, TabBar::Dots, (max(), max()))),
+            infinite
+        );
+    }
+}
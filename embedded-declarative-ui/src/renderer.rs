@@ -0,0 +1,220 @@
+use crate::{
+    environment::EnvironmentValues,
+    state::{self, RedrawTracker},
+    view::View,
+};
+use core::future::Future;
+use embedded_graphics::{
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point},
+    primitives::Rectangle,
+};
+
+/// Owns the display target and redraws the root view whenever the application state changes.
+///
+/// The first frame is drawn in full. Afterwards only the regions of [`state::Watch`] views
+/// whose state changed are redrawn, see [`RedrawTracker`]. Full redraws clear the target to
+/// the theme background first, if there is a theme.
+pub struct Renderer<Target, Color>
+where
+    Target: DrawTarget<Color = Color> + OriginDimensions,
+    Color: PixelColor,
+{
+    target: Target,
+    environment: EnvironmentValues<Color>,
+    needs_full_redraw: bool,
+    tracker: RedrawTracker,
+}
+
+#[allow(dead_code)]
+impl<Target, Color> Renderer<Target, Color>
+where
+    Target: DrawTarget<Color = Color> + OriginDimensions,
+    Target::Error: 'static,
+    Color: PixelColor,
+{
+    pub fn new(target: Target, environment: EnvironmentValues<Color>) -> Self {
+        Self {
+            target,
+            environment,
+            needs_full_redraw: true,
+            tracker: RedrawTracker::new(),
+        }
+    }
+
+    pub fn target(&mut self) -> &mut Target {
+        &mut self.target
+    }
+
+    pub fn into_target(self) -> Target {
+        self.target
+    }
+
+    /// Changes the environment and redraws the whole screen on the next render.
+    pub fn set_environment(&mut self, environment: EnvironmentValues<Color>) {
+        self.environment = environment;
+        self.invalidate();
+    }
+
+    /// Redraws the whole screen on the next render, e.g. after changing state that isn't
+    /// read through a [`state::Watch`] or another view reporting it.
+    pub fn invalidate(&mut self) {
+        self.needs_full_redraw = true;
+    }
+
+    /// Draws `view` to fill the target and returns the region that changed, if any.
+    pub async fn render<V>(&mut self, view: &V) -> Option<Rectangle>
+    where
+        V: View<Color>,
+    {
+        let size = self.target.size();
+
+        if !self.needs_full_redraw {
+            return self
+                .tracker
+                .redraw_dirty(view, size, &self.environment, &mut self.target)
+                .await;
+        }
+
+        self.needs_full_redraw = false;
+        let frame = Rectangle::new(Point::zero(), size);
+
+        if let Some(theme) = &self.environment.theme {
+            _ = self.target.fill_solid(&frame, theme.background);
+        }
+
+        self.tracker
+            .draw(view, size, &self.environment, &mut self.target)
+            .await;
+        Some(frame)
+    }
+
+    /// Whether `view` has changed since it was last rendered, e.g. because an
+    /// [`Animated`](crate::animation::Animated) value it watches is still animating.
+    pub async fn needs_render<V>(&self, view: &V) -> bool
+    where
+        V: View<Color>,
+    {
+        self.needs_full_redraw
+            || state::dirty_region(view, self.target.size(), &self.environment)
+                .await
+                .is_some()
+    }
+
+    /// Renders the view built by `build`, passes the changed region to `flush` and waits for
+    /// `wait_for_change` before rendering again, forever.
+    ///
+    /// While the view is still changing, e.g. during animations, it waits for `wait_for_frame`
+    /// instead, which paces the frames, e.g. `|| Timer::after_millis(16)` with Embassy.
+    ///
+    /// With the `embassy` feature, `wait_for_change` is typically `|| signal.wait()` for a
+    /// `Signal` passed to [`state::State::with_notifier`].
+    pub async fn run<V, Build, Wait, WaitFuture, Frame, FrameFuture, Flush>(
+        &mut self,
+        build: Build,
+        mut wait_for_change: Wait,
+        mut wait_for_frame: Frame,
+        mut flush: Flush,
+    ) -> !
+    where
+        V: View<Color>,
+        Build: Fn() -> V,
+        Wait: FnMut() -> WaitFuture,
+        WaitFuture: Future<Output = ()>,
+        Frame: FnMut() -> FrameFuture,
+        FrameFuture: Future<Output = ()>,
+        Flush: FnMut(&mut Target, Rectangle),
+    {
+        loop {
+            let view = build();
+
+            if let Some(region) = self.render(&view).await {
+                flush(&mut self.target, region);
+            }
+
+            match self.needs_render(&view).await {
+                true => wait_for_frame().await,
+                false => wait_for_change().await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        alignment::{HorizontalAlignment, VerticalAlignment},
+        blocking::block_on,
+        fill::Fill,
+        focus::{FocusId, FocusState, Focusable},
+        frame::{Dimension, Frame},
+        hstack::HStack,
+        navigation::{NavigationStack, NavigationState},
+    };
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    fn renderer() -> Renderer<MockDisplay<BinaryColor>, BinaryColor> {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Renderer::new(display, EnvironmentValues::default())
+    }
+
+    fn square(color: BinaryColor) -> impl View<BinaryColor> {
+        Frame::new(
+            Dimension::Constant(4),
+            Dimension::Constant(4),
+            HorizontalAlignment::Left,
+            VerticalAlignment::Top,
+            Fill::new(color),
+        )
+    }
+
+    fn color(focused: bool) -> BinaryColor {
+        match focused {
+            true => BinaryColor::On,
+            false => BinaryColor::Off,
+        }
+    }
+
+    #[test]
+    fn focus_changes_are_redrawn() {
+        let focus_state = FocusState::new();
+        let view = HStack::new(
+            0,
+            VerticalAlignment::Top,
+            (
+                Focusable::new(&focus_state, FocusId(1), |focused| square(color(focused))),
+                Focusable::new(&focus_state, FocusId(2), |focused| square(color(focused))),
+            ),
+        );
+        let mut renderer = renderer();
+
+        assert!(block_on(renderer.render(&view)).is_some());
+        assert_eq!(block_on(renderer.render(&view)), None);
+
+        focus_state.set_focused(Some(FocusId(2)));
+        assert!(block_on(renderer.render(&view)).is_some());
+        assert_eq!(
+            renderer.target().get_pixel(Point::new(5, 1)),
+            Some(BinaryColor::On)
+        );
+        assert_eq!(block_on(renderer.render(&view)), None);
+    }
+
+    #[test]
+    fn navigation_between_screens_of_the_same_layout_is_redrawn() {
+        let navigation_state = NavigationState::<bool, 2>::new(false);
+        let view = NavigationStack::new(&navigation_state, |screen| square(color(screen)));
+        let mut renderer = renderer();
+
+        block_on(renderer.render(&view));
+        assert_eq!(block_on(renderer.render(&view)), None);
+
+        navigation_state.push(true).unwrap();
+        assert!(block_on(renderer.render(&view)).is_some());
+        assert_eq!(
+            renderer.target().get_pixel(Point::new(1, 1)),
+            Some(BinaryColor::On)
+        );
+    }
+}
@@ -0,0 +1,520 @@
+use crate::{
+    draw_target::{LayoutDrawTarget, Mask, MaskedDrawTarget},
+    environment::EnvironmentValues,
+    layout::{self, LayoutVisitor},
+    proposed_size::ProposedSize,
+    view::View,
+};
+use core::{cell::Cell, marker::PhantomData};
+use embedded_graphics::{
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// Receives a notification whenever a [`State`] changes, e.g. to wake up the render loop.
+pub trait Notify {
+    fn notify(&self);
+}
+
+#[cfg(feature = "embassy")]
+impl<M> Notify for embassy_sync::signal::Signal<M, ()>
+where
+    M: embassy_sync::blocking_mutex::raw::RawMutex,
+{
+    fn notify(&self) {
+        self.signal(());
+    }
+}
+
+/// State whose views need to be redrawn after it changed, see [`Watch`].
+pub trait Observable {
+    /// Whether the value changed since the views watching it were last drawn.
+    fn is_dirty(&self) -> bool;
+
+    fn mark_clean(&self);
+}
+
+/// Observable value that can be read by a [`Watch`], such as a [`State`] or an
+/// [`Animated`](crate::animation::Animated) value.
+pub trait Readable<T>: Observable {
+    fn get(&self) -> T;
+}
+
+/// Value shared between the application and the views showing it, tracking whether it
+/// changed since it was last drawn.
+///
+/// New states start out dirty so their views are drawn at least once.
+pub struct State<'a, T>
+where
+    T: Copy + PartialEq,
+{
+    value: Cell<T>,
+    dirty: Cell<bool>,
+    notifier: Option<&'a dyn Notify>,
+}
+
+#[allow(dead_code)]
+impl<'a, T> State<'a, T>
+where
+    T: Copy + PartialEq,
+{
+    pub fn new(value: T) -> Self {
+        Self {
+            value: Cell::new(value),
+            dirty: Cell::new(true),
+            notifier: None,
+        }
+    }
+
+    /// State calling `notifier` whenever its value changes.
+    pub fn with_notifier(value: T, notifier: &'a dyn Notify) -> Self {
+        Self {
+            notifier: Some(notifier),
+            ..Self::new(value)
+        }
+    }
+
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    /// Stores `value`, invalidating the views watching this state if it differs from the current one.
+    pub fn set(&self, value: T) {
+        if self.value.replace(value) == value {
+            return;
+        }
+
+        self.dirty.set(true);
+
+        if let Some(notifier) = self.notifier {
+            notifier.notify();
+        }
+    }
+
+    pub fn update(&self, update: impl FnOnce(T) -> T) {
+        self.set(update(self.get()));
+    }
+}
+
+impl<T> Observable for State<'_, T>
+where
+    T: Copy + PartialEq,
+{
+    fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    fn mark_clean(&self) {
+        self.dirty.set(false);
+    }
+}
+
+impl<T> Readable<T> for State<'_, T>
+where
+    T: Copy + PartialEq,
+{
+    fn get(&self) -> T {
+        self.value.get()
+    }
+}
+
+/// Builds its content from the current value of `source`, recording the dependency so that
+/// only the frame of this view is redrawn by [`RedrawTracker`] after the value changed.
+pub struct Watch<'a, T, Content, ContentView, Color>
+where
+    Content: Fn(T) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    source: &'a dyn Readable<T>,
+    content: Content,
+    _marker: PhantomData<Color>,
+}
+
+impl<'a, T, Content, ContentView, Color> Watch<'a, T, Content, ContentView, Color>
+where
+    Content: Fn(T) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(source: &'a dyn Readable<T>, content: Content) -> Self {
+        Self {
+            source,
+            content,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, Content, ContentView, Color> View<Color> for Watch<'_, T, Content, ContentView, Color>
+where
+    Content: Fn(T) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        if let Some(visitor) = &mut draw_target.visitor {
+            visitor.observed(Rectangle::new(draw_target.offset, size), self.source);
+        }
+
+        draw_target
+            .draw_child(
+                &(self.content)(self.source.get()),
+                Point::zero(),
+                size,
+                environment,
+            )
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        (self.content)(self.source.get())
+            .size(proposed_size, environment)
+            .await
+    }
+}
+
+/// Hash of the frames of a view tree, leaving out the content of [`Watch`] views, whose
+/// changes are covered by their own frames.
+struct LayoutFingerprint {
+    hash: u64,
+    depth: usize,
+    watched_depth: Option<usize>,
+}
+
+impl LayoutFingerprint {
+    fn new() -> Self {
+        Self {
+            hash: 0xcbf2_9ce4_8422_2325,
+            depth: 0,
+            watched_depth: None,
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.hash = (self.hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn enter(&mut self, frame: Rectangle) {
+        self.depth += 1;
+
+        if self.watched_depth.is_none() {
+            self.write(&frame.top_left.x.to_le_bytes());
+            self.write(&frame.top_left.y.to_le_bytes());
+            self.write(&frame.size.width.to_le_bytes());
+            self.write(&frame.size.height.to_le_bytes());
+        }
+    }
+
+    fn exit(&mut self) {
+        self.depth -= 1;
+
+        match self.watched_depth {
+            Some(depth) if self.depth < depth => self.watched_depth = None,
+            Some(_) => {}
+            None => self.write(&[0]),
+        }
+    }
+
+    fn observed(&mut self) {
+        self.watched_depth.get_or_insert(self.depth);
+    }
+}
+
+struct DirtyCollector {
+    region: Option<Rectangle>,
+    layout: LayoutFingerprint,
+}
+
+impl LayoutVisitor for DirtyCollector {
+    fn enter(&mut self, frame: Rectangle) {
+        self.layout.enter(frame);
+    }
+
+    fn exit(&mut self) {
+        self.layout.exit();
+    }
+
+    fn observed(&mut self, frame: Rectangle, state: &dyn Observable) {
+        self.layout.observed();
+
+        if !state.is_dirty() {
+            return;
+        }
+
+        self.region = Some(match self.region {
+            Some(region) => union(region, frame),
+            None => frame,
+        });
+    }
+}
+
+struct Cleaner {
+    layout: LayoutFingerprint,
+}
+
+impl LayoutVisitor for Cleaner {
+    fn enter(&mut self, frame: Rectangle) {
+        self.layout.enter(frame);
+    }
+
+    fn exit(&mut self) {
+        self.layout.exit();
+    }
+
+    fn observed(&mut self, _frame: Rectangle, state: &dyn Observable) {
+        self.layout.observed();
+        state.mark_clean();
+    }
+}
+
+fn union(a: Rectangle, b: Rectangle) -> Rectangle {
+    let (Some(a_bottom_right), Some(b_bottom_right)) = (a.bottom_right(), b.bottom_right()) else {
+        return if a.is_zero_sized() { b } else { a };
+    };
+
+    Rectangle::with_corners(
+        a.top_left.component_min(b.top_left),
+        a_bottom_right.component_max(b_bottom_right),
+    )
+}
+
+/// Returns the bounding box of the [`Watch`] views of `view` placed into `size` whose
+/// state changed since they were last drawn, or `None` if nothing needs to be redrawn.
+pub async fn dirty_region<Color, V>(
+    view: &V,
+    size: Size,
+    environment: &EnvironmentValues<Color>,
+) -> Option<Rectangle>
+where
+    Color: PixelColor,
+    V: View<Color>,
+{
+    let mut collector = DirtyCollector {
+        region: None,
+        layout: LayoutFingerprint::new(),
+    };
+    layout::visit(view, size, environment, &mut collector).await;
+    collector.region
+}
+
+/// Redraws the parts of a view affected by changed states, remembering the layout of the
+/// last frame drawn.
+///
+/// Only the frames of views whose state changed are redrawn, such as [`Watch`] views, as long
+/// as the layout outside of them stays the same. When a change moves or resizes other views,
+/// e.g. a watched text growing and pushing its siblings aside, the whole view is redrawn
+/// instead.
+///
+/// Redrawn pixels are cleared to the theme background first, if there is a theme. Without
+/// one, watched content must paint all of its frame, e.g. on an opaque
+/// [`Background`](crate::background::Background), or pixels of its previous value remain.
+#[derive(Default)]
+pub struct RedrawTracker {
+    layout: Option<u64>,
+}
+
+impl RedrawTracker {
+    pub fn new() -> Self {
+        Self { layout: None }
+    }
+
+    /// Draws all of `view` placed into `size` into `target` and marks every state it watches
+    /// as clean.
+    pub async fn draw<Color, V, Target, Error>(
+        &mut self,
+        view: &V,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        target: &mut Target,
+    ) where
+        Color: PixelColor,
+        V: View<Color>,
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let mut cleaner = Cleaner {
+            layout: LayoutFingerprint::new(),
+        };
+        let mut draw_target = LayoutDrawTarget {
+            original_draw_target: target,
+            offset: Point::zero(),
+            visitor: Some(&mut cleaner),
+            alpha: None,
+        };
+
+        layout::draw_root(view, size, environment, &mut draw_target).await;
+        self.layout = Some(cleaner.layout.hash);
+    }
+
+    /// Redraws the part of `view` placed into `size` affected by changed states into
+    /// `target`, leaving all other pixels untouched, and returns the redrawn region.
+    ///
+    /// Views overlapping the region are drawn again as well, so their pixels stay consistent.
+    pub async fn redraw_dirty<Color, V, Target, Error>(
+        &mut self,
+        view: &V,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        target: &mut Target,
+    ) -> Option<Rectangle>
+    where
+        Color: PixelColor,
+        V: View<Color>,
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let mut collector = DirtyCollector {
+            region: None,
+            layout: LayoutFingerprint::new(),
+        };
+        layout::visit(view, size, environment, &mut collector).await;
+
+        let region = match self.layout == Some(collector.layout.hash) {
+            true => collector.region?,
+            false => Rectangle::new(Point::zero(), size),
+        };
+
+        if let Some(theme) = &environment.theme {
+            _ = target.fill_solid(&region, theme.background);
+        }
+
+        let mut clipped_target = MaskedDrawTarget {
+            original_draw_target: target,
+            mask: Mask {
+                clip: Some(region),
+                coverage: None,
+                inverted: false,
+            },
+        };
+        self.draw(view, size, environment, &mut clipped_target)
+            .await;
+
+        Some(region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        alignment::{HorizontalAlignment, VerticalAlignment},
+        blocking::block_on,
+        draw_target::Inert,
+        fill::Fill,
+        frame::{Dimension, Frame},
+        vstack::VStack,
+    };
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    const SIZE: Size = Size::new(10, 10);
+
+    fn bar(height: u32, color: BinaryColor) -> impl View<BinaryColor> {
+        Frame::new(
+            Dimension::Constant(4),
+            Dimension::Constant(height),
+            HorizontalAlignment::Leading,
+            VerticalAlignment::Top,
+            Fill::new(color),
+        )
+    }
+
+    fn color(on: bool) -> BinaryColor {
+        match on {
+            true => BinaryColor::On,
+            false => BinaryColor::Off,
+        }
+    }
+
+    fn display() -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display
+    }
+
+    #[test]
+    fn watch_changes_redraw_only_their_frame() {
+        let first = State::new(false);
+        let second = State::new(false);
+        let view = VStack::new(
+            0,
+            HorizontalAlignment::Leading,
+            (
+                Watch::new(&first, |on| bar(2, color(on))),
+                Watch::new(&second, |on| bar(2, color(on))),
+            ),
+        );
+        let environment = EnvironmentValues::default();
+        let mut display = display();
+        let mut tracker = RedrawTracker::new();
+
+        block_on(tracker.draw(&view, SIZE, &environment, &mut display));
+        assert_eq!(
+            block_on(tracker.redraw_dirty(&view, SIZE, &environment, &mut display)),
+            None
+        );
+
+        second.set(true);
+        let region = block_on(tracker.redraw_dirty(&view, SIZE, &environment, &mut display));
+        assert_eq!(
+            region,
+            Some(Rectangle::new(Point::new(0, 2), Size::new(4, 2)))
+        );
+        assert_eq!(display.get_pixel(Point::new(1, 3)), Some(BinaryColor::On));
+        assert!(!second.is_dirty());
+    }
+
+    #[test]
+    fn watched_views_moving_their_siblings_redraw_everything() {
+        let height = State::new(2);
+        let view = VStack::new(
+            0,
+            HorizontalAlignment::Leading,
+            (
+                Watch::new(&height, |height| bar(height, BinaryColor::Off)),
+                bar(2, BinaryColor::On),
+            ),
+        );
+        let environment = EnvironmentValues::default();
+        let mut display = display();
+        let mut tracker = RedrawTracker::new();
+
+        block_on(tracker.draw(&view, SIZE, &environment, &mut display));
+
+        height.set(4);
+        let region = block_on(tracker.redraw_dirty(&view, SIZE, &environment, &mut display));
+        assert_eq!(region, Some(Rectangle::new(Point::zero(), SIZE)));
+        assert_eq!(display.get_pixel(Point::new(1, 5)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn watch_in_inert_content_is_redrawn_and_cleaned() {
+        let on = State::new(false);
+        let watch = Watch::new(&on, |on| bar(2, color(on)));
+        let view = Inert(&watch);
+        let environment = EnvironmentValues::default();
+        let mut display = display();
+        let mut tracker = RedrawTracker::new();
+
+        block_on(tracker.draw(&view, SIZE, &environment, &mut display));
+        assert!(!on.is_dirty());
+
+        on.set(true);
+        assert!(block_on(tracker.redraw_dirty(&view, SIZE, &environment, &mut display)).is_some());
+        assert!(!on.is_dirty());
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{
+    alignment::VerticalAlignment,
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    hstack::{HStack, HViewTuple},
+    padding::Padding,
+    proposed_size::{ProposedSize, shrink},
+    text::Text,
+    view::View,
+};
+use core::marker::PhantomData;
+use embedded_graphics::{
+    Drawable,
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Primitive, Size},
+    primitives::{Circle, Line, PrimitiveStyle},
+};
+
+const DOT_DIAMETER: u32 = 5;
+const DOT_SPACING: u32 = 3;
+const LABEL_SPACING: u32 = 4;
+
+/// Indicator drawn below the pages of a [`TabView`].
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+pub enum TabBar {
+    Hidden,
+    /// A row of labels, one per page, with the selected one underlined.
+    Labels(&'static [&'static str]),
+    /// A row of dots, one per page, with the selected one filled.
+    Dots,
+}
+
+/// Shows the page at `selected` out of a tuple of pages, with a [`TabBar`] below it.
+///
+/// Read the selection through a [`Watch`](crate::state::Watch), e.g.
+/// `Watch::new(&selected, |selected| TabView::new(selected, tab_bar, pages))`, so switching
+/// pages redraws it.
+pub struct TabView<Pages, Color>
+where
+    Pages: TabPages<Color>,
+    Color: PixelColor,
+{
+    selected: usize,
+    tab_bar: TabBar,
+    pages: Pages,
+    _marker: PhantomData<Color>,
+}
+
+impl<Pages, Color> TabView<Pages, Color>
+where
+    Pages: TabPages<Color>,
+    Color: PixelColor,
+{
+    /// `selected` is clamped to the last page.
+    pub fn new(selected: usize, tab_bar: TabBar, pages: Pages) -> Self {
+        Self {
+            selected: selected.min(Pages::COUNT - 1),
+            tab_bar,
+            pages,
+            _marker: PhantomData,
+        }
+    }
+
+    fn bar(&self) -> Option<HStack<Pages::TabRow, Color>> {
+        let spacing = match self.tab_bar {
+            TabBar::Hidden => return None,
+            TabBar::Labels(_) => LABEL_SPACING,
+            TabBar::Dots => DOT_SPACING,
+        };
+
+        Some(HStack::new(
+            spacing,
+            VerticalAlignment::Center,
+            self.pages.tab_row(self.selected, self.tab_bar),
+        ))
+    }
+}
+
+impl<Pages, Color> View<Color> for TabView<Pages, Color>
+where
+    Pages: TabPages<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let mut page_height = size.height;
+
+        if let Some(bar) = self.bar() {
+            let bar_size = bar
+                .size(ProposedSize::new(Some(size.width), None), environment)
+                .await;
+            page_height = page_height.saturating_sub(bar_size.height);
+
+            let offset = Point::new(
+                (size.width as i32 - bar_size.width as i32) / 2,
+                page_height as i32,
+            );
+            draw_target
+                .draw_child(&bar, offset, bar_size, environment)
+                .await;
+        }
+
+        let page_proposed_size = ProposedSize::new(Some(size.width), Some(page_height));
+        let page_size = self
+            .pages
+            .page_size(self.selected, page_proposed_size, environment)
+            .await;
+
+        self.pages
+            .draw_page(self.selected, page_size, environment, draw_target)
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        let bar_size = match self.bar() {
+            Some(bar) => {
+                bar.size(ProposedSize::new(proposed_size.width, None), environment)
+                    .await
+            }
+            None => Size::zero(),
+        };
+
+        let page_proposed_size = ProposedSize::new(
+            proposed_size.width,
+            shrink(proposed_size.height, bar_size.height),
+        );
+        let page_size = self
+            .pages
+            .page_size(self.selected, page_proposed_size, environment)
+            .await;
+
+        Size::new(
+            page_size.width.max(bar_size.width),
+            page_size.height.saturating_add(bar_size.height),
+        )
+    }
+}
+
+/// Entry of the tab bar for the page at `index`.
+pub struct TabItem<Color>
+where
+    Color: PixelColor,
+{
+    index: usize,
+    selected: bool,
+    tab_bar: TabBar,
+    _marker: PhantomData<Color>,
+}
+
+impl<Color> TabItem<Color>
+where
+    Color: PixelColor,
+{
+    fn new(index: usize, selected: usize, tab_bar: TabBar) -> Self {
+        Self {
+            index,
+            selected: index == selected,
+            tab_bar,
+            _marker: PhantomData,
+        }
+    }
+
+    fn label(&self) -> Padding<Text<Color, &'static str>, Color> {
+        let label = match self.tab_bar {
+            TabBar::Labels(labels) => labels.get(self.index).copied().unwrap_or_default(),
+            _ => "",
+        };
+
+        Padding::vertical(2, Text::plain(label))
+    }
+}
+
+impl<Color> View<Color> for TabItem<Color>
+where
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let color = match self.selected {
+            true => environment.accent_color.or(environment.foreground_color),
+            false => environment.foreground_color,
+        };
+
+        match self.tab_bar {
+            TabBar::Hidden => {}
+            TabBar::Labels(_) => {
+                draw_target
+                    .draw_child(&self.label(), Point::zero(), size, environment)
+                    .await;
+
+                if let (true, Some(color)) = (self.selected, color) {
+                    let y = draw_target.offset.y + size.height as i32 - 1;
+                    _ = Line::new(
+                        Point::new(draw_target.offset.x, y),
+                        Point::new(draw_target.offset.x + size.width as i32 - 1, y),
+                    )
+                    .into_styled(PrimitiveStyle::with_stroke(color, 1))
+                    .draw(draw_target.original_draw_target);
+                }
+            }
+            TabBar::Dots => {
+                if let Some(color) = color {
+                    let style = match self.selected {
+                        true => PrimitiveStyle::with_fill(color),
+                        false => PrimitiveStyle::with_stroke(color, 1),
+                    };
+                    _ = Circle::new(draw_target.offset, DOT_DIAMETER)
+                        .into_styled(style)
+                        .draw(draw_target.original_draw_target);
+                }
+            }
+        }
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        match self.tab_bar {
+            TabBar::Hidden => Size::zero(),
+            TabBar::Labels(_) => self.label().size(proposed_size, environment).await,
+            TabBar::Dots => Size::new(DOT_DIAMETER, DOT_DIAMETER),
+        }
+    }
+}
+
+/// Tuple of pages shown by a [`TabView`].
+pub trait TabPages<Color: PixelColor> {
+    const COUNT: usize;
+
+    type TabRow: HViewTuple<Color>;
+
+    fn tab_row(&self, selected: usize, tab_bar: TabBar) -> Self::TabRow;
+
+    async fn page_size(
+        &self,
+        index: usize,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size;
+
+    async fn draw_page<Target, Error>(
+        &self,
+        index: usize,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static;
+}
+
+macro_rules! tab_item {
+    ($name:ident) => {
+        TabItem<Color>
+    };
+}
+
+macro_rules! impl_tab_pages {
+    ($count:literal; $($index:literal $name:ident),*) => {
+        impl<Color, $($name),*> TabPages<Color> for ($($name,)*)
+        where
+            Color: PixelColor,
+            $($name: View<Color>),*
+        {
+            const COUNT: usize = $count;
+
+            type TabRow = ($(tab_item!($name),)*);
+
+            fn tab_row(&self, selected: usize, tab_bar: TabBar) -> Self::TabRow {
+                ($(TabItem::<Color>::new($index, selected, tab_bar),)*)
+            }
+
+            async fn page_size(
+                &self,
+                index: usize,
+                proposed_size: ProposedSize,
+                environment: &EnvironmentValues<Color>,
+            ) -> Size {
+                #[allow(non_snake_case)]
+                let ($($name,)*) = self;
+
+                match index {
+                    $($index => $name.size(proposed_size, environment).await,)*
+                    _ => Size::zero(),
+                }
+            }
+
+            async fn draw_page<Target, Error>(
+                &self,
+                index: usize,
+                size: Size,
+                environment: &EnvironmentValues<Color>,
+                draw_target: &mut LayoutDrawTarget<'_, Target>,
+            ) where
+                Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+                Error: 'static,
+            {
+                #[allow(non_snake_case)]
+                let ($($name,)*) = self;
+
+                match index {
+                    $($index => draw_target.draw_child($name, Point::zero(), size, environment).await,)*
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+impl_tab_pages!(1; 0 V1);
+impl_tab_pages!(2; 0 V1, 1 V2);
+impl_tab_pages!(3; 0 V1, 1 V2, 2 V3);
+impl_tab_pages!(4; 0 V1, 1 V2, 2 V3, 3 V4);
+impl_tab_pages!(5; 0 V1, 1 V2, 2 V3, 3 V4, 4 V5);
+impl_tab_pages!(6; 0 V1, 1 V2, 2 V3, 3 V4, 4 V5, 5 V6);
+impl_tab_pages!(7; 0 V1, 1 V2, 2 V3, 3 V4, 4 V5, 5 V6, 6 V7);
+impl_tab_pages!(8; 0 V1, 1 V2, 2 V3, 3 V4, 4 V5, 5 V6, 6 V7, 7 V8);
+impl_tab_pages!(9; 0 V1, 1 V2, 2 V3, 3 V4, 4 V5, 5 V6, 6 V7, 7 V8, 8 V9);
+impl_tab_pages!(10; 0 V1, 1 V2, 2 V3, 3 V4, 4 V5, 5 V6, 6 V7, 7 V8, 8 V9, 9 V10);
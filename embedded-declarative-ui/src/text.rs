@@ -1,61 +1,102 @@
-use crate::{draw_target::LayoutDrawTarget, view::View};
-use core::{fmt::Display, marker::PhantomData};
+use crate::{
+    draw_target::LayoutDrawTarget,
+    environment::{EnvironmentValues, LayoutDirection},
+    proposed_size::ProposedSize,
+    view::View,
+};
+use core::fmt::Display;
 use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
 use u8g2_fonts::{
     Font, FontRenderer,
     types::{FontColor, VerticalPosition},
 };
 
-pub struct Text<Color, FONT, CONTENT>
+/// Text drawn with an explicit color and font, or the foreground color and font of the environment.
+pub struct Text<Color, CONTENT>
 where
     Color: PixelColor,
-    FONT: Font,
     CONTENT: Display,
 {
     content: CONTENT,
-    color: Color,
-    _marker: PhantomData<FONT>,
+    color: Option<Color>,
+    font: Option<FontRenderer>,
 }
 
-impl<Color, FONT, CONTENT> Text<Color, FONT, CONTENT>
+impl<Color, CONTENT> Text<Color, CONTENT>
 where
     Color: PixelColor,
-    FONT: Font,
     CONTENT: Display,
 {
-    pub fn new(content: CONTENT, color: Color, _font: FONT) -> Self {
+    pub fn new<FONT: Font>(content: CONTENT, color: Color, _font: FONT) -> Self {
+        Text {
+            content,
+            color: Some(color),
+            font: Some(FontRenderer::new::<FONT>()),
+        }
+    }
+
+    /// Text taking its color and font from the environment.
+    pub fn plain(content: CONTENT) -> Self {
         Text {
             content,
-            color,
-            _marker: PhantomData,
+            color: None,
+            font: None,
         }
     }
+
+    fn font<'a>(&'a self, environment: &'a EnvironmentValues<Color>) -> Option<&'a FontRenderer> {
+        self.font.as_ref().or(environment.font.as_ref())
+    }
 }
 
-impl<Color, FONT, CONTENT> View<Color> for Text<Color, FONT, CONTENT>
+impl<Color, CONTENT> View<Color> for Text<Color, CONTENT>
 where
     Color: PixelColor,
-    FONT: Font,
     CONTENT: Display,
 {
-    async fn draw<Target, Error>(&self, _size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
-        let renderer = FontRenderer::new::<FONT>();
+        let (Some(renderer), Some(color)) = (
+            self.font(environment),
+            self.color.or(environment.foreground_color),
+        ) else {
+            return;
+        };
+
+        // Right-to-left text starts at the trailing edge of the frame it was given.
+        let x_offset = match environment.layout_direction {
+            LayoutDirection::LeftToRight => 0,
+            LayoutDirection::RightToLeft => {
+                let text_size = self.size(size.into(), environment).await;
+                size.width as i32 - text_size.width as i32
+            }
+        };
 
         _ = renderer.render(
             format_args!("{}", self.content),
-            Point::new(0, 0),
+            Point::new(x_offset, 0),
             VerticalPosition::Top,
-            FontColor::Transparent(self.color),
+            FontColor::Transparent(color),
             draw_target,
         );
     }
 
-    async fn size(&self, _available_size: Size) -> Size {
-        let renderer = FontRenderer::new::<FONT>();
+    async fn size(
+        &self,
+        _proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        let Some(renderer) = self.font(environment) else {
+            return Size::zero();
+        };
+
         let dimensions = renderer
             .get_rendered_dimensions(
                 format_args!("{}", self.content),
@@ -0,0 +1,68 @@
+use embedded_graphics::pixelcolor::Rgb888;
+use u8g2_fonts::FontRenderer;
+
+/// Named semantic colors and fonts read by the built-in views when no explicit style is given.
+///
+/// Set it on the root environment with [`EnvironmentValues::set_theme`](crate::environment::EnvironmentValues::set_theme)
+/// and swap it at runtime by drawing with another theme.
+#[derive(Clone)]
+pub struct Theme<Color> {
+    /// Color behind the content of a screen.
+    pub background: Color,
+    /// Color of raised elements such as buttons.
+    pub surface: Color,
+    /// Color of text and icons.
+    pub foreground: Color,
+    /// Color of highlighted elements such as the focused button.
+    pub accent: Color,
+    pub border: Color,
+    /// Color of text and borders of disabled elements.
+    pub disabled: Color,
+    pub font: FontRenderer,
+    pub title_font: FontRenderer,
+}
+
+#[allow(dead_code)]
+impl<Color> Theme<Color>
+where
+    Color: From<Rgb888>,
+{
+    pub fn light(font: FontRenderer, title_font: FontRenderer) -> Self {
+        Self {
+            background: Rgb888::new(0xFF, 0xFF, 0xFF).into(),
+            surface: Rgb888::new(0xEE, 0xEE, 0xEE).into(),
+            foreground: Rgb888::new(0x00, 0x00, 0x00).into(),
+            accent: Rgb888::new(0x00, 0x5F, 0xCC).into(),
+            border: Rgb888::new(0x80, 0x80, 0x80).into(),
+            disabled: Rgb888::new(0xA8, 0xA8, 0xA8).into(),
+            font,
+            title_font,
+        }
+    }
+
+    pub fn dark(font: FontRenderer, title_font: FontRenderer) -> Self {
+        Self {
+            background: Rgb888::new(0x12, 0x12, 0x12).into(),
+            surface: Rgb888::new(0x2C, 0x2C, 0x2C).into(),
+            foreground: Rgb888::new(0xEE, 0xEE, 0xEE).into(),
+            accent: Rgb888::new(0x4D, 0xA3, 0xFF).into(),
+            border: Rgb888::new(0x5A, 0x5A, 0x5A).into(),
+            disabled: Rgb888::new(0x66, 0x66, 0x66).into(),
+            font,
+            title_font,
+        }
+    }
+
+    pub fn high_contrast(font: FontRenderer, title_font: FontRenderer) -> Self {
+        Self {
+            background: Rgb888::new(0x00, 0x00, 0x00).into(),
+            surface: Rgb888::new(0x00, 0x00, 0x00).into(),
+            foreground: Rgb888::new(0xFF, 0xFF, 0xFF).into(),
+            accent: Rgb888::new(0xFF, 0xFF, 0x00).into(),
+            border: Rgb888::new(0xFF, 0xFF, 0xFF).into(),
+            disabled: Rgb888::new(0x80, 0x80, 0x80).into(),
+            font,
+            title_font,
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use crate::{
+    animation::{Animated, Clock, Easing},
+    draw_target::{Inert, LayoutDrawTarget, Mask},
+    environment::{EnvironmentValues, LayoutDirection},
+    proposed_size::ProposedSize,
+    state::Observable,
+    view::View,
+};
+use core::{cell::Cell, marker::PhantomData};
+use embedded_graphics::{
+    prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// Edge a view enters from.
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Edge {
+    Leading,
+    Trailing,
+    Top,
+    Bottom,
+}
+
+impl Edge {
+    /// Offset of a view of `size` moved `amount` of the way off the frame towards this edge.
+    fn offset(self, size: Size, amount: f32, layout_direction: LayoutDirection) -> Point {
+        let width = (size.width as f32 * amount) as i32;
+        let height = (size.height as f32 * amount) as i32;
+
+        match (self, layout_direction) {
+            (Edge::Leading, LayoutDirection::LeftToRight)
+            | (Edge::Trailing, LayoutDirection::RightToLeft) => Point::new(-width, 0),
+            (Edge::Leading, LayoutDirection::RightToLeft)
+            | (Edge::Trailing, LayoutDirection::LeftToRight) => Point::new(width, 0),
+            (Edge::Top, _) => Point::new(0, -height),
+            (Edge::Bottom, _) => Point::new(0, height),
+        }
+    }
+}
+
+/// How the new content of a [`Transition`] replaces the old one.
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransitionStyle {
+    /// The new content slides in from the edge, covering the old one.
+    Slide(Edge),
+    /// The new content slides in from the edge, pushing the old one out the opposite edge.
+    Push(Edge),
+    /// The new content appears pixel by pixel in an ordered dither pattern, which also
+    /// works on monochrome displays.
+    DitherFade,
+}
+
+/// Content key shown by a [`Transition`] and the transition from the previous one.
+pub struct TransitionState<'a, Key>
+where
+    Key: Copy + PartialEq,
+{
+    current: Cell<Key>,
+    previous: Cell<Option<Key>>,
+    progress: Animated<'a, f32>,
+}
+
+#[allow(dead_code)]
+impl<'a, Key> TransitionState<'a, Key>
+where
+    Key: Copy + PartialEq,
+{
+    pub fn new(key: Key, duration_ms: u32, easing: Easing, clock: &'a dyn Clock) -> Self {
+        Self {
+            current: Cell::new(key),
+            previous: Cell::new(None),
+            progress: Animated::new(1.0, duration_ms, easing, clock),
+        }
+    }
+
+    pub fn current(&self) -> Key {
+        self.current.get()
+    }
+
+    /// Transitions to the content for `key`, starting from the current content.
+    pub fn show(&self, key: Key) {
+        if key == self.current.get() {
+            return;
+        }
+
+        self.previous.set(Some(self.current.replace(key)));
+        self.progress.set(0.0);
+        self.progress.animate_to(1.0);
+    }
+
+    /// Shows the content for `key` right away.
+    pub fn set(&self, key: Key) {
+        self.current.set(key);
+        self.previous.set(None);
+        self.progress.set(1.0);
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.progress.is_animating()
+    }
+
+    /// Previous key and the eased progress of the transition from it, while animating.
+    fn transition(&self) -> Option<(Key, f32)> {
+        match self.progress.is_animating() {
+            true => self
+                .previous
+                .get()
+                .map(|previous| (previous, self.progress.value())),
+            false => None,
+        }
+    }
+}
+
+impl<Key> Observable for TransitionState<'_, Key>
+where
+    Key: Copy + PartialEq,
+{
+    fn is_dirty(&self) -> bool {
+        self.progress.is_dirty()
+    }
+
+    fn mark_clean(&self) {
+        self.progress.mark_clean();
+    }
+}
+
+/// Shows the content built by `content` for the current key of a [`TransitionState`],
+/// animating between the old and new content whenever the key changes.
+///
+/// `content` typically switches on the key with `view_match!`. During a transition only the
+/// new content takes part in hit testing and focus.
+///
+/// While animating, the frame is cleared to the theme background, if there is a theme.
+/// Without one, the content must paint all of its frame, e.g. on an opaque
+/// [`Background`](crate::background::Background), or pixels of earlier frames remain.
+pub struct Transition<'a, 'b, Key, Content, ContentView, Color>
+where
+    Key: Copy + PartialEq,
+    Content: Fn(Key) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    state: &'a TransitionState<'b, Key>,
+    style: TransitionStyle,
+    content: Content,
+    _marker: PhantomData<Color>,
+}
+
+impl<'a, 'b, Key, Content, ContentView, Color> Transition<'a, 'b, Key, Content, ContentView, Color>
+where
+    Key: Copy + PartialEq,
+    Content: Fn(Key) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    pub fn new(
+        state: &'a TransitionState<'b, Key>,
+        style: TransitionStyle,
+        content: Content,
+    ) -> Self {
+        Self {
+            state,
+            style,
+            content,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Key, Content, ContentView, Color> View<Color>
+    for Transition<'_, '_, Key, Content, ContentView, Color>
+where
+    Key: Copy + PartialEq,
+    Content: Fn(Key) -> ContentView,
+    ContentView: View<Color>,
+    Color: PixelColor,
+{
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
+        Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
+        Error: 'static,
+    {
+        let frame = Rectangle::new(draw_target.offset, size);
+        if let Some(visitor) = &mut draw_target.visitor {
+            visitor.observed(frame, self.state);
+        }
+
+        let current = (self.content)(self.state.current());
+
+        let Some((previous, progress)) = self.state.transition() else {
+            draw_target
+                .draw_child(&current, Point::zero(), size, environment)
+                .await;
+            return;
+        };
+
+        let previous = (self.content)(previous);
+        let clipped = Mask {
+            clip: Some(frame),
+            coverage: None,
+            inverted: false,
+        };
+        let direction = environment.layout_direction;
+
+        if let Some(theme) = &environment.theme {
+            _ = draw_target.fill_solid(&Rectangle::new(Point::zero(), size), theme.background);
+        }
+
+        // The old and new content never draw the same pixel, so opaque content repaints the
+        // whole frame even without a theme background to clear it.
+        let (previous_offset, previous_mask, current_offset, current_mask) = match self.style {
+            TransitionStyle::Slide(edge) => {
+                let current_offset = edge.offset(size, 1.0 - progress, direction);
+                let uncovered = Rectangle::new(
+                    frame.top_left + current_offset - edge.offset(size, 1.0, direction),
+                    size,
+                );
+
+                (
+                    Point::zero(),
+                    Mask {
+                        clip: Some(uncovered.intersection(&frame)),
+                        ..clipped
+                    },
+                    current_offset,
+                    clipped,
+                )
+            }
+            TransitionStyle::Push(edge) => {
+                let current_offset = edge.offset(size, 1.0 - progress, direction);
+
+                (
+                    current_offset - edge.offset(size, 1.0, direction),
+                    clipped,
+                    current_offset,
+                    clipped,
+                )
+            }
+            TransitionStyle::DitherFade => {
+                let coverage = Some((progress.clamp(0.0, 1.0) * 16.0) as u8);
+
+                (
+                    Point::zero(),
+                    Mask {
+                        coverage,
+                        inverted: true,
+                        ..clipped
+                    },
+                    Point::zero(),
+                    Mask {
+                        coverage,
+                        ..clipped
+                    },
+                )
+            }
+        };
+
+        draw_target
+            .draw_child_masked(
+                &Inert(&previous),
+                previous_offset,
+                size,
+                environment,
+                previous_mask,
+            )
+            .await;
+        draw_target
+            .draw_child_masked(&current, current_offset, size, environment, current_mask)
+            .await;
+    }
+
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        (self.content)(self.state.current())
+            .size(proposed_size, environment)
+            .await
+    }
+}
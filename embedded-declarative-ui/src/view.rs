@@ -1,29 +1,61 @@
-use crate::draw_target::LayoutDrawTarget;
-use embedded_graphics::prelude::{DrawTarget, OriginDimensions, Size};
+use crate::{
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    layout::{self, LayoutNode},
+    proposed_size::ProposedSize,
+};
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Size};
 
 pub trait View<Color> {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static;
 
-    async fn size(&self, available_size: Size) -> Size;
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size;
+
+    /// Writes the frames of this view and its descendants placed into `size` into `nodes`,
+    /// without drawing any pixels. See [`layout::layout`].
+    async fn layout(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        nodes: &mut [LayoutNode],
+    ) -> usize
+    where
+        Self: Sized,
+        Color: PixelColor,
+    {
+        layout::layout(self, size, environment, nodes).await
+    }
 }
 
-impl<'a, C, V> View<C> for &'a V
+impl<C, V> View<C> for &V
 where
     V: View<C>,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<C>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = C, Error = Error> + OriginDimensions,
         Error: 'static,
     {
-        (**self).draw(size, draw_target).await
+        (**self).draw(size, environment, draw_target).await
     }
 
-    async fn size(&self, available_size: Size) -> Size {
-        (**self).size(available_size).await
+    async fn size(&self, proposed_size: ProposedSize, environment: &EnvironmentValues<C>) -> Size {
+        (**self).size(proposed_size, environment).await
     }
 }
 
@@ -1,4 +1,10 @@
-use crate::{alignment::HorizontalAlignment, draw_target::LayoutDrawTarget, view::View};
+use crate::{
+    alignment::HorizontalAlignment,
+    draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    proposed_size::{ProposedSize, shrink},
+    view::View,
+};
 use core::{cmp::max, marker::PhantomData};
 use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
 
@@ -33,29 +39,51 @@ where
     Views: VViewTuple<Color>,
     Color: PixelColor,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
         self.views
-            .draw_all(size, self.spacing, self.horizontal_alignment, draw_target)
+            .draw_all(
+                size,
+                self.spacing,
+                self.horizontal_alignment,
+                environment,
+                draw_target,
+            )
             .await;
     }
 
-    async fn size(&self, available_size: Size) -> Size {
-        self.views.total_size(available_size, self.spacing).await
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.views
+            .total_size(proposed_size, self.spacing, environment)
+            .await
     }
 }
 
 pub trait VViewTuple<Color: PixelColor> {
-    async fn total_size(&self, available_size: Size, spacing: u32) -> Size;
+    async fn total_size(
+        &self,
+        proposed_size: ProposedSize,
+        spacing: u32,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size;
 
     async fn draw_all<Target, Error>(
         &self,
         available_size: Size,
         spacing: u32,
         horizontal_alignment: HorizontalAlignment,
+        environment: &EnvironmentValues<Color>,
         draw_target: &mut LayoutDrawTarget<'_, Target>,
     ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
@@ -69,7 +97,12 @@ macro_rules! impl_vview_tuple {
             Color: PixelColor,
             $($name: View<Color>),*
         {
-            async fn total_size(&self, available_size: Size, spacing: u32) -> Size {
+            async fn total_size(
+                &self,
+                proposed_size: ProposedSize,
+                spacing: u32,
+                environment: &EnvironmentValues<Color>,
+            ) -> Size {
                 #[allow(non_snake_case)]
                 let ($($name,)*) = self;
                 let mut total_height = 0;
@@ -77,18 +110,18 @@ macro_rules! impl_vview_tuple {
                 let mut count = 0;
 
                 $(
-                    let remaining_size = Size {
-                        width: available_size.width,
-                        height: available_size.height - total_height,
-                    };
-                    let s = $name.size(remaining_size).await;
-                    total_height += s.height;
+                    let remaining_size = ProposedSize::new(
+                        proposed_size.width,
+                        shrink(proposed_size.height, total_height),
+                    );
+                    let s = $name.size(remaining_size, environment).await;
+                    total_height = total_height.saturating_add(s.height);
                     max_width = max(max_width, s.width);
                     count += 1;
                 )*
 
                 if count > 1 {
-                    total_height += spacing * (count - 1);
+                    total_height = total_height.saturating_add(spacing.saturating_mul(count - 1));
                 }
 
                 Size { width: max_width, height: total_height }
@@ -100,6 +133,7 @@ macro_rules! impl_vview_tuple {
                 available_size: Size,
                 spacing: u32,
                 horizontal_alignment: HorizontalAlignment,
+                environment: &EnvironmentValues<Color>,
                 draw_target: &mut LayoutDrawTarget<'_, Target>,
             ) where
                 Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
@@ -110,28 +144,29 @@ macro_rules! impl_vview_tuple {
                 let mut current_y_offset = 0;
 
                 $(
-                    let remaining_size = Size {
-                        width: available_size.width,
-                        height: available_size.height - current_y_offset,
-                    };
-                    let view_size = $name.size(remaining_size).await;
-
-                    let x_offset = match horizontal_alignment {
-                        HorizontalAlignment::Left => 0,
-                        HorizontalAlignment::Right => available_size.width - view_size.width,
-                        HorizontalAlignment::Center => (available_size.width - view_size.width) / 2,
-                    };
-
-                    let mut child_target = LayoutDrawTarget {
-                        original_draw_target: draw_target.original_draw_target,
-                        offset: Point {
-                            x: draw_target.offset.x + x_offset as i32,
-                            y: draw_target.offset.y + current_y_offset as i32,
-                        },
-                    };
-
-                    $name.draw(view_size, &mut child_target).await;
-                    current_y_offset += view_size.height + spacing;
+                    let remaining_size = ProposedSize::new(
+                        Some(available_size.width),
+                        shrink(Some(available_size.height), current_y_offset),
+                    );
+                    let view_size = $name.size(remaining_size, environment).await;
+
+                    let x_offset = horizontal_alignment.offset(
+                        available_size.width,
+                        view_size.width,
+                        environment.layout_direction,
+                    );
+
+                    draw_target
+                        .draw_child(
+                            $name,
+                            Point::new(x_offset, current_y_offset as i32),
+                            view_size,
+                            environment,
+                        )
+                        .await;
+                    current_y_offset = current_y_offset
+                        .saturating_add(view_size.height)
+                        .saturating_add(spacing);
                 )*
             }
         }
@@ -1,10 +1,12 @@
 use crate::{
-    alignment::{HorizontalAlignment, VerticalAlignment},
+    alignment::{HorizontalAlignment, VerticalAlignment, aligned_offset},
     draw_target::LayoutDrawTarget,
+    environment::EnvironmentValues,
+    proposed_size::ProposedSize,
     view::View,
 };
 use core::{cmp::max, marker::PhantomData};
-use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Point, Size};
+use embedded_graphics::prelude::{DrawTarget, OriginDimensions, PixelColor, Size};
 
 pub struct ZStack<Views, Color>
 where
@@ -41,8 +43,12 @@ where
     Views: ZViewTuple<Color>,
     Color: PixelColor,
 {
-    async fn draw<Target, Error>(&self, size: Size, draw_target: &mut LayoutDrawTarget<'_, Target>)
-    where
+    async fn draw<Target, Error>(
+        &self,
+        size: Size,
+        environment: &EnvironmentValues<Color>,
+        draw_target: &mut LayoutDrawTarget<'_, Target>,
+    ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
         Error: 'static,
     {
@@ -51,24 +57,34 @@ where
                 size,
                 self.horizontal_alignment,
                 self.vertical_alignment,
+                environment,
                 draw_target,
             )
             .await;
     }
 
-    async fn size(&self, available_size: Size) -> Size {
-        self.views.total_size(available_size).await
+    async fn size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size {
+        self.views.total_size(proposed_size, environment).await
     }
 }
 
 pub trait ZViewTuple<Color: PixelColor> {
-    async fn total_size(&self, available_size: Size) -> Size;
+    async fn total_size(
+        &self,
+        proposed_size: ProposedSize,
+        environment: &EnvironmentValues<Color>,
+    ) -> Size;
 
     async fn draw_all<Target, Error>(
         &self,
         available_size: Size,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
+        environment: &EnvironmentValues<Color>,
         draw_target: &mut LayoutDrawTarget<'_, Target>,
     ) where
         Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
@@ -82,14 +98,18 @@ macro_rules! impl_zview_tuple {
             Color: PixelColor,
             $($name: View<Color>),*
         {
-            async fn total_size(&self, available_size: Size) -> Size {
+            async fn total_size(
+                &self,
+                proposed_size: ProposedSize,
+                environment: &EnvironmentValues<Color>,
+            ) -> Size {
                 #[allow(non_snake_case)]
                 let ($($name,)*) = self;
                 let mut max_width = 0;
                 let mut max_height = 0;
 
                 $(
-                    let s = $name.size(available_size).await;
+                    let s = $name.size(proposed_size, environment).await;
                     max_width = max(max_width, s.width);
                     max_height = max(max_height, s.height);
                 )*
@@ -102,6 +122,7 @@ macro_rules! impl_zview_tuple {
                 available_size: Size,
                 horizontal_alignment: HorizontalAlignment,
                 vertical_alignment: VerticalAlignment,
+                environment: &EnvironmentValues<Color>,
                 draw_target: &mut LayoutDrawTarget<'_, Target>,
             ) where
                 Target: DrawTarget<Color = Color, Error = Error> + OriginDimensions,
@@ -111,29 +132,19 @@ macro_rules! impl_zview_tuple {
                 let ($($name,)*) = self;
 
                 $(
-                    let view_size = $name.size(available_size).await;
-
-                    let x_offset = match horizontal_alignment {
-                        HorizontalAlignment::Left => 0,
-                        HorizontalAlignment::Right => available_size.width as i32 - view_size.width as i32,
-                        HorizontalAlignment::Center => (available_size.width as i32 - view_size.width as i32) / 2,
-                    };
-
-                    let y_offset = match vertical_alignment {
-                        VerticalAlignment::Top => 0,
-                        VerticalAlignment::Bottom => available_size.height as i32 - view_size.height as i32,
-                        VerticalAlignment::Center => (available_size.height as i32 - view_size.height as i32) / 2,
-                    };
-
-                    let mut child_target = LayoutDrawTarget {
-                        original_draw_target: draw_target.original_draw_target,
-                        offset: Point {
-                            x: draw_target.offset.x + x_offset,
-                            y: draw_target.offset.y + y_offset,
-                        },
-                    };
-
-                    $name.draw(view_size, &mut child_target).await;
+                    let view_size = $name.size(available_size.into(), environment).await;
+
+                    let offset = aligned_offset(
+                        horizontal_alignment,
+                        vertical_alignment,
+                        available_size,
+                        view_size,
+                        environment.layout_direction,
+                    );
+
+                    draw_target
+                        .draw_child($name, offset, view_size, environment)
+                        .await;
                 )*
             }
         }
@@ -34,20 +34,22 @@ pub fn derive_view(input: TokenStream) -> TokenStream {
             async fn draw<Target, Error>(
                 &self,
                 size: embedded_graphics::prelude::Size,
+                environment: &embedded_declarative_ui::environment::EnvironmentValues<Color>,
                 draw_target: &mut embedded_declarative_ui::draw_target::LayoutDrawTarget<'_, Target>,
             ) where
                 Target: embedded_graphics::prelude::DrawTarget<Color = Color, Error = Error>
                     + embedded_graphics::prelude::OriginDimensions,
                 Error: 'static,
             {
-                self.body().await.draw(size, draw_target).await
+                self.body().await.draw(size, environment, draw_target).await
             }
 
             async fn size(
                 &self,
-                available_size: embedded_graphics::prelude::Size,
+                proposed_size: embedded_declarative_ui::proposed_size::ProposedSize,
+                environment: &embedded_declarative_ui::environment::EnvironmentValues<Color>,
             ) -> embedded_graphics::prelude::Size {
-                self.body().await.size(available_size).await
+                self.body().await.size(proposed_size, environment).await
             }
         }
     };